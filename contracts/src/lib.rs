@@ -7,10 +7,13 @@ pub mod quantum_validator;
 pub mod thermo_state_tracker;
 pub mod energy_ledger_manager;
 pub mod consensus_validator;
+pub mod pipeline;
 pub mod types;
 pub mod utils;
 
 pub use quantum_validator::QuantumValidator;
-pub use thermo_state_tracker::ThermoStateTracker;
+pub use thermo_state_tracker::{ThermoStateTracker, ThermodynamicStateBuilder};
 pub use energy_ledger_manager::EnergyLedgerManager;
-pub use consensus_validator::ConsensusValidator;
\ No newline at end of file
+pub use consensus_validator::ConsensusValidator;
+pub use pipeline::ValidationPipeline;
+pub use types::{ValidatableInput, Validator};
\ No newline at end of file
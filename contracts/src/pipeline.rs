@@ -0,0 +1,210 @@
+//! Validation Pipeline
+//!
+//! Chains several validators over the same input, either stopping at the first
+//! failure or collecting every failure
+
+use crate::types::{Clock, SystemClock, ValidationFailure, ValidationResult, Validator};
+
+/// Ordered list of validators sharing an input and error type
+pub struct ValidationPipeline<I, E> {
+    validators: Vec<Box<dyn Validator<Input = I, Error = E>>>,
+    clock: Box<dyn Clock>,
+}
+
+impl<I, E: ValidationFailure> Default for ValidationPipeline<I, E> {
+    fn default() -> Self {
+        ValidationPipeline {
+            validators: Vec::new(),
+            clock: Box::new(SystemClock),
+        }
+    }
+}
+
+impl<I, E: ValidationFailure> ValidationPipeline<I, E> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Appends a validator to the end of the pipeline
+    pub fn with_validator<V>(mut self, validator: V) -> Self
+    where
+        V: Validator<Input = I, Error = E> + 'static,
+    {
+        self.validators.push(Box::new(validator));
+        self
+    }
+    
+    /// Uses `clock` to timestamp the aggregate result
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+    
+    /// Runs the validators in order and returns the first error encountered
+    ///
+    /// On success the aggregate confidence is the lowest individual confidence
+    /// and the consensus lists every validator that ran.
+    pub fn run_fail_fast(&self, input: &I) -> Result<ValidationResult, E> {
+        let mut results = Vec::with_capacity(self.validators.len());
+        
+        for validator in &self.validators {
+            results.push(validator.validate(input)?);
+        }
+        
+        Ok(self.aggregate(&results, &[]))
+    }
+    
+    /// Runs every validator and returns the aggregate result alongside all errors
+    ///
+    /// The aggregate is invalid if any validator failed, reporting the first
+    /// failure's check and every error message.
+    pub fn run_collect_all(&self, input: &I) -> (ValidationResult, Vec<E>) {
+        let mut results = Vec::new();
+        let mut errors = Vec::new();
+        
+        for validator in &self.validators {
+            match validator.validate(input) {
+                Ok(result) => results.push(result),
+                Err(error) => errors.push(error),
+            }
+        }
+        
+        (self.aggregate(&results, &errors), errors)
+    }
+    
+    /// Combines the individual outcomes into one result
+    fn aggregate(&self, results: &[ValidationResult], errors: &[E]) -> ValidationResult {
+        let timestamp = self.clock.now_secs();
+        let validator_consensus = results
+            .iter()
+            .flat_map(|result| result.validator_consensus.iter().cloned())
+            .collect();
+        let notes = results
+            .iter()
+            .flat_map(|result| result.notes.iter().cloned())
+            .collect();
+        
+        if let Some(first) = errors.first() {
+            let messages: Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+            return ValidationResult {
+                is_valid: false,
+                error_message: Some(messages.join("; ")),
+                confidence_score: 0.0,
+                validator_consensus,
+                timestamp,
+                failed_check: Some(first.failed_check()),
+                notes,
+            };
+        }
+        
+        ValidationResult {
+            is_valid: true,
+            error_message: None,
+            confidence_score: results
+                .iter()
+                .map(|result| result.confidence_score)
+                .fold(1.0, f64::min),
+            validator_consensus,
+            timestamp,
+            failed_check: None,
+            notes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantum_validator::{QuantumValidationError, QuantumValidator};
+    use crate::types::{FailedCheck, PhysicalConstants, QuantumTransition};
+    use fabric_contract_api::contract::Contract;
+    
+    /// Rejects photons below a minimum energy, as a detector cut-off would
+    struct DetectorCutoff {
+        min_energy: f64,
+    }
+    
+    impl Validator for DetectorCutoff {
+        type Input = QuantumTransition;
+        type Error = QuantumValidationError;
+        
+        fn validate(
+            &self,
+            input: &QuantumTransition,
+        ) -> Result<ValidationResult, QuantumValidationError> {
+            if input.photon_energy < self.min_energy {
+                return Err(QuantumValidationError::InvalidEnergyLevel { level: input.photon_energy });
+            }
+            
+            Ok(ValidationResult {
+                is_valid: true,
+                error_message: None,
+                confidence_score: 0.9,
+                validator_consensus: vec!["detector_cutoff".to_string()],
+                timestamp: 1640995200,
+                failed_check: None,
+                notes: Vec::new(),
+            })
+        }
+    }
+    
+    fn transition(photon_energy: f64) -> QuantumTransition {
+        let frequency = photon_energy * PhysicalConstants::ELECTRON_CHARGE /
+            PhysicalConstants::PLANCK_CONSTANT;
+        
+        QuantumTransition {
+            electron_id: "e-1".to_string(),
+            initial_energy: 2.0,
+            final_energy: 1.0,
+            photon_energy,
+            wavelength: PhysicalConstants::SPEED_OF_LIGHT / frequency * 1e9,
+            frequency,
+            timestamp: 1640995200,
+            validated: false,
+            validator_id: "test".to_string(),
+            relativistic: false,
+            declared_kind: None,
+            source_velocity_ms: None,
+            energy_uncertainty: None,
+            frequency_uncertainty: None,
+            nuclear_mass_kg: None,
+            orbital_angular_momentum: None,
+        }
+    }
+    
+    fn pipeline() -> ValidationPipeline<QuantumTransition, QuantumValidationError> {
+        ValidationPipeline::new()
+            .with_validator(QuantumValidator::new())
+            .with_validator(DetectorCutoff { min_energy: 1.5 })
+    }
+    
+    #[test]
+    fn test_all_validators_pass() {
+        let pipeline = ValidationPipeline::new()
+            .with_validator(QuantumValidator::new())
+            .with_validator(DetectorCutoff { min_energy: 0.5 });
+        let result = pipeline.run_fail_fast(&transition(1.0)).unwrap();
+        assert!(result.is_valid);
+        assert_eq!(result.confidence_score, 0.9);
+        assert_eq!(result.validator_consensus, ["quantum_validator", "detector_cutoff"]);
+    }
+    
+    #[test]
+    fn test_fail_fast_stops_at_first_failure() {
+        // Violates energy conservation and falls below the detector cut-off
+        let result = pipeline().run_fail_fast(&transition(1.2));
+        assert!(matches!(result, Err(QuantumValidationError::EnergyConservationViolation { .. })));
+    }
+    
+    #[test]
+    fn test_collect_all_gathers_every_failure() {
+        let (result, errors) = pipeline().run_collect_all(&transition(1.2));
+        
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(errors[0], QuantumValidationError::EnergyConservationViolation { .. }));
+        assert!(matches!(errors[1], QuantumValidationError::InvalidEnergyLevel { .. }));
+        assert!(!result.is_valid);
+        assert_eq!(result.failed_check, Some(FailedCheck::EnergyConservation));
+        assert_eq!(result.error_message.unwrap().matches("; ").count(), 1);
+    }
+}
\ No newline at end of file
@@ -2,10 +2,17 @@
 //! 
 //! Validates quantum energy transitions using fundamental physics principles
 
-use crate::types::{QuantumTransition, ValidationResult, PhysicalConstants};
+use crate::types::{
+    QuantumTransition, TransitionKind, ValidationResult, FailedCheck, FailureObserver,
+    ConfidencePrecision, ConstantsSet, Tolerances, Validator, ValidationFailure,
+    CheckExplanation, ValidationExplanation, Clock, SystemClock, MolecularTransition,
+    KnownTransition, TraceEvent,
+};
 use serde::{Deserialize, Serialize};
 use fabric_contract_api::contract::Contract;
 use fabric_contract_api::info::Info;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -16,51 +23,626 @@ pub enum QuantumValidationError {
     SelectionRulesViolation,
     #[error("Invalid energy level: {level}")]
     InvalidEnergyLevel { level: f64 },
-    #[error("Frequency calculation error")]
-    FrequencyCalculationError,
+    #[error("Initial and final levels coincide at {level} eV")]
+    NoLevelChange { level: f64 },
+    #[error("Non-finite input in field {field}")]
+    NonFiniteInput { field: &'static str },
+    #[error("Frequency mismatch: expected {expected} Hz, got {actual} Hz")]
+    FrequencyMismatch { expected: f64, actual: f64 },
+    #[error("Wavelength mismatch: expected {expected} nm, got {actual} nm")]
+    WavelengthMismatch { expected: f64, actual: f64 },
+    #[error("Fine-structure splitting mismatch: expected {expected} eV, got {actual} eV")]
+    FineStructureMismatch { expected: f64, actual: f64 },
+    #[error("Transition declared as {declared:?} but energies describe {derived:?}")]
+    DirectionMismatch { declared: TransitionKind, derived: Option<TransitionKind> },
+    #[error("Invalid intensity parameters: g_initial {g_initial}, g_final {g_final}, temperature {temperature} K")]
+    InvalidIntensityParameters { g_initial: u32, g_final: u32, temperature: f64 },
+    #[error("Timestamp at index {index} is not after its predecessor: {prev} then {curr}")]
+    NonMonotonicTimestamp { index: usize, prev: u64, curr: u64 },
+    #[error("Invalid uncertainty in field {field}: {value}")]
+    InvalidUncertainty { field: &'static str, value: f64 },
+    #[error("Invalid source velocity: {velocity} m/s")]
+    InvalidSourceVelocity { velocity: f64 },
+    #[error("Invalid nuclear mass: {mass} kg")]
+    InvalidNuclearMass { mass: f64 },
+    #[error("Invalid principal quantum number: {n}")]
+    InvalidPrincipalQuantumNumber { n: u32 },
+    #[error("Confidence {confidence} is below the required {threshold}")]
+    InsufficientConfidence { confidence: f64, threshold: f64 },
+    #[error("Invalid confidence threshold: {threshold}")]
+    InvalidConfidenceThreshold { threshold: f64 },
+    #[error("Charge not conserved: expected final charge {expected}, got {actual}")]
+    ChargeNotConserved { expected: i64, actual: i64 },
+    #[error("Compton shift mismatch: expected {expected_shift} m, got {actual_shift} m")]
+    ComptonKinematicsViolation { expected_shift: f64, actual_shift: f64 },
+    #[error("Rotational selection rule violated: J {j_initial} → {j_final}")]
+    RotationalSelectionRuleViolation { j_initial: u32, j_final: u32 },
+    #[error("Invalid molecular constants: ν = {harmonic_frequency} Hz, B = {rotational_constant} eV")]
+    InvalidMolecularConstants { harmonic_frequency: f64, rotational_constant: f64 },
 }
 
+impl QuantumValidationError {
+    /// Returns the validation rule this error corresponds to
+    pub fn failed_check(&self) -> FailedCheck {
+        match self {
+            QuantumValidationError::EnergyConservationViolation { .. } => FailedCheck::EnergyConservation,
+            QuantumValidationError::SelectionRulesViolation |
+            QuantumValidationError::RotationalSelectionRuleViolation { .. } => FailedCheck::SelectionRules,
+            QuantumValidationError::InvalidEnergyLevel { .. } |
+            QuantumValidationError::NoLevelChange { .. } |
+            QuantumValidationError::InvalidPrincipalQuantumNumber { .. } => FailedCheck::InvalidEnergyLevel,
+            QuantumValidationError::NonFiniteInput { .. } => FailedCheck::NonFiniteInput,
+            QuantumValidationError::FrequencyMismatch { .. } => FailedCheck::FrequencyMismatch,
+            QuantumValidationError::WavelengthMismatch { .. } => FailedCheck::WavelengthMismatch,
+            QuantumValidationError::FineStructureMismatch { .. } => FailedCheck::FineStructure,
+            QuantumValidationError::DirectionMismatch { .. } => FailedCheck::TransitionDirection,
+            QuantumValidationError::NonMonotonicTimestamp { .. } => FailedCheck::TemporalOrdering,
+            QuantumValidationError::InvalidIntensityParameters { .. } |
+            QuantumValidationError::InvalidUncertainty { .. } |
+            QuantumValidationError::InvalidSourceVelocity { .. } |
+            QuantumValidationError::InvalidConfidenceThreshold { .. } |
+            QuantumValidationError::InvalidMolecularConstants { .. } => FailedCheck::PhysicalConstraints,
+            QuantumValidationError::InsufficientConfidence { .. } => FailedCheck::LowConfidence,
+            QuantumValidationError::ChargeNotConserved { .. } => FailedCheck::ChargeConservation,
+            QuantumValidationError::ComptonKinematicsViolation { .. } => FailedCheck::ComptonKinematics,
+            QuantumValidationError::InvalidNuclearMass { .. } => FailedCheck::NuclearMass,
+        }
+    }
+}
+
+impl QuantumTransition {
+    /// Checks internal consistency independent of the full validation pipeline
+    ///
+    /// Verifies that all fields are finite, the frequency matches the photon
+    /// energy, and the wavelength matches the frequency, using the default
+    /// validator tolerances.
+    pub fn check_invariants(&self) -> Result<(), QuantumValidationError> {
+        let validator = QuantumValidator::new();
+        validator.validate_inputs(self)?;
+        validator.validate_frequency_energy_relationship(self)
+    }
+}
+
+/// Least-recently-used cache of successful validation results
+///
+/// A hit restamps its entry with a fresh sequence number and queues it again
+/// rather than searching the queue, so lookups are O(1). Queue slots whose
+/// sequence no longer matches their entry are stale and skipped on eviction.
+struct TransitionCache {
+    capacity: usize,
+    sequence: u64,
+    entries: HashMap<QuantumTransition, (ValidationResult, u64)>,
+    recency: VecDeque<(u64, QuantumTransition)>,
+}
+
+impl TransitionCache {
+    fn new(capacity: usize) -> Self {
+        TransitionCache {
+            capacity,
+            sequence: 0,
+            entries: HashMap::with_capacity(capacity),
+            recency: VecDeque::with_capacity(capacity),
+        }
+    }
+    
+    fn get(&mut self, transition: &QuantumTransition) -> Option<ValidationResult> {
+        let (result, stamp) = self.entries.get_mut(transition)?;
+        self.sequence += 1;
+        *stamp = self.sequence;
+        let result = result.clone();
+        
+        self.recency.push_back((self.sequence, transition.clone()));
+        self.compact();
+        Some(result)
+    }
+    
+    fn insert(&mut self, transition: QuantumTransition, result: ValidationResult) {
+        if self.capacity == 0 {
+            return;
+        }
+        
+        if !self.entries.contains_key(&transition) && self.entries.len() == self.capacity {
+            self.evict_oldest();
+        }
+        
+        self.sequence += 1;
+        self.recency.push_back((self.sequence, transition.clone()));
+        self.entries.insert(transition, (result, self.sequence));
+        self.compact();
+    }
+    
+    /// Checks whether a queue slot still records the latest use of its transition
+    fn is_current(&self, sequence: u64, transition: &QuantumTransition) -> bool {
+        self.entries.get(transition).map(|(_, stamp)| *stamp) == Some(sequence)
+    }
+    
+    /// Removes the least recently used entry, skipping stale queue slots
+    fn evict_oldest(&mut self) {
+        while let Some((sequence, transition)) = self.recency.pop_front() {
+            if self.is_current(sequence, &transition) {
+                self.entries.remove(&transition);
+                return;
+            }
+        }
+    }
+    
+    /// Drops stale queue slots once they outnumber the live entries
+    ///
+    /// Afterwards the queue holds at most `capacity` slots, so this runs at
+    /// most once per `capacity` operations and stays amortized O(1).
+    fn compact(&mut self) {
+        if self.recency.len() <= 2 * self.capacity {
+            return;
+        }
+        
+        let entries = &self.entries;
+        self.recency.retain(|(sequence, transition)| {
+            entries.get(transition).map(|(_, stamp)| *stamp) == Some(*sequence)
+        });
+    }
+    
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+    }
+}
+
+/// Note attached to results for forbidden transitions accepted with a penalty
+pub const FORBIDDEN_TRANSITION_NOTE: &str = "forbidden_transition";
+
+/// Confidence multiplier for selection-rule-forbidden transitions, which proceed
+/// through higher-order multipoles many orders of magnitude more slowly
+pub const FORBIDDEN_TRANSITION_PENALTY: f64 = 0.1;
+
 #[derive(Info)]
 pub struct QuantumValidator {
-    tolerance: f64,
+    tolerances: Tolerances,
+    confidence_precision: ConfidencePrecision,
+    constants: ConstantsSet,
+    on_failure: Option<FailureObserver>,
+    clock: Box<dyn Clock>,
+    cache: Option<Mutex<TransitionCache>>,
+    known_transitions: Vec<KnownTransition>,
+    allow_forbidden_with_penalty: bool,
 }
 
 impl Contract for QuantumValidator {
     fn new() -> Self {
         QuantumValidator {
-            tolerance: 1e-15, // eV
+            tolerances: Tolerances::default(),
+            confidence_precision: ConfidencePrecision::default(),
+            constants: ConstantsSet::codata_2018(),
+            on_failure: None,
+            clock: Box::new(SystemClock),
+            cache: None,
+            known_transitions: hydrogen_series(),
+            allow_forbidden_with_penalty: false,
         }
     }
 }
 
+/// Lyman, Balmer, and Paschen lines of hydrogen up to n = 6
+fn hydrogen_series() -> Vec<KnownTransition> {
+    let level = |n: u32| 13.6057 / (n as f64).powi(2); // eV
+    
+    [("Lyman", 1), ("Balmer", 2), ("Paschen", 3)]
+        .into_iter()
+        .flat_map(|(series, lower)| {
+            ((lower + 1)..=6).map(move |upper| KnownTransition {
+                initial_energy: level(lower),
+                final_energy: level(upper),
+                tolerance: 0.1,
+                series: series.to_string(),
+            })
+        })
+        .collect()
+}
+
+impl ValidationFailure for QuantumValidationError {
+    fn failed_check(&self) -> FailedCheck {
+        QuantumValidationError::failed_check(self)
+    }
+}
+
+impl Validator for QuantumValidator {
+    type Input = QuantumTransition;
+    type Error = QuantumValidationError;
+    
+    fn validate(&self, input: &QuantumTransition) -> Result<ValidationResult, QuantumValidationError> {
+        self.validate_transition(input)
+    }
+}
+
 impl QuantumValidator {
+    /// Uses the given set of physical constants, e.g. an older CODATA revision
+    ///
+    /// Cached results are discarded since every expected frequency and wavelength changes.
+    pub fn with_constants(mut self, constants: ConstantsSet) -> Self {
+        self.reconfigure(|validator| validator.constants = constants);
+        self
+    }
+    
+    /// Uses `clock` to timestamp results, e.g. a `FixedClock` for deterministic output
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.reconfigure(|validator| validator.clock = Box::new(clock));
+        self
+    }
+    
+    /// Enables an LRU cache of successful validation results holding up to `capacity` entries
+    pub fn with_cache(mut self, capacity: usize) -> Self {
+        self.cache = Some(Mutex::new(TransitionCache::new(capacity)));
+        self
+    }
+    
+    /// Replaces every tolerance at once, e.g. to match an instrument's precision
+    ///
+    /// Cached results are discarded since they were computed under the old tolerances.
+    pub fn with_tolerances(mut self, tolerances: Tolerances) -> Self {
+        self.reconfigure(|validator| validator.tolerances = tolerances);
+        self
+    }
+    
+    /// Sets the energy conservation tolerance in eV, discarding any cached results
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        self.reconfigure(|validator| validator.tolerances.energy = tolerance);
+    }
+    
+    /// Adds a reference transition to the table consulted for the confidence boost
+    ///
+    /// Cached results are discarded since their confidence may change.
+    pub fn add_known_transition(&mut self, transition: KnownTransition) {
+        self.reconfigure(|validator| validator.known_transitions.push(transition));
+    }
+    
+    /// Applies a settings change and discards every result cached under the old settings
+    ///
+    /// Every builder and setter goes through here so no setting can leave the
+    /// cache serving results it would no longer produce.
+    fn reconfigure(&mut self, change: impl FnOnce(&mut Self)) {
+        change(self);
+        if let Some(cache) = &mut self.cache {
+            cache.get_mut().unwrap().clear();
+        }
+    }
+    
+    /// Sets the number of decimal places reported confidence scores are rounded to
+    ///
+    /// Cached results are discarded since they were rounded at the old precision.
+    pub fn with_confidence_precision(mut self, precision: ConfidencePrecision) -> Self {
+        self.reconfigure(|validator| validator.confidence_precision = precision);
+        self
+    }
+    
+    /// Accepts selection-rule violations as forbidden transitions instead of rejecting them
+    ///
+    /// Forbidden lines still occur through magnetic dipole or electric quadrupole
+    /// decay at much lower rates, so they pass with confidence scaled by
+    /// `FORBIDDEN_TRANSITION_PENALTY` and a `forbidden_transition` entry in `notes`.
+    /// Invalid energy levels are rejected regardless. Cached results are discarded.
+    pub fn with_forbidden_transitions(mut self, allow_with_penalty: bool) -> Self {
+        self.reconfigure(|validator| validator.allow_forbidden_with_penalty = allow_with_penalty);
+        self
+    }
+    
+    /// Registers a callback invoked for every failed result in batch validation
+    pub fn with_failure_observer<F>(mut self, observer: F) -> Self
+    where
+        F: Fn(&ValidationResult) + Send + Sync + 'static,
+    {
+        self.reconfigure(|validator| validator.on_failure = Some(Box::new(observer)));
+        self
+    }
+    
     /// Validates a quantum energy transition
     pub fn validate_transition(
         &self,
         transition: &QuantumTransition,
     ) -> Result<ValidationResult, QuantumValidationError> {
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => return self.validate_transition_uncached(transition),
+        };
+        
+        if let Some(result) = cache.lock().unwrap().get(transition) {
+            return Ok(result);
+        }
+        
+        let result = self.validate_transition_uncached(transition)?;
+        cache.lock().unwrap().insert(transition.clone(), result.clone());
+        Ok(result)
+    }
+    
+    /// Validates a transition and returns it marked as validated by this validator
+    ///
+    /// On success `validated` is set and `validator_id` names this validator; on
+    /// failure the error is returned and no validated copy is produced.
+    pub fn validate_transition_owned(
+        &self,
+        mut transition: QuantumTransition,
+    ) -> Result<QuantumTransition, QuantumValidationError> {
+        self.validate_transition(&transition)?;
+        
+        transition.validated = true;
+        transition.validator_id = "quantum_validator".to_string();
+        Ok(transition)
+    }
+    
+    /// Runs the full validation pipeline without consulting the cache
+    fn validate_transition_uncached(
+        &self,
+        transition: &QuantumTransition,
+    ) -> Result<ValidationResult, QuantumValidationError> {
+        // Reject NaN, infinite, and out-of-range fields before any comparisons
+        self.validate_inputs(transition)?;
+        
         // Validate energy conservation
         self.validate_energy_conservation(transition)?;
         
-        // Validate quantum selection rules
-        self.validate_selection_rules(transition)?;
+        // Validate that both levels are bound states and differ
+        self.validate_energy_levels(transition)?;
+        
+        // Validate quantum selection rules, tolerating violations when penalized instead
+        let forbidden = self.validate_selection_rules_penalized(transition)?;
+        
+        // Validate declared emission/absorption direction
+        self.validate_direction(transition)?;
         
         // Validate frequency-energy relationship
         self.validate_frequency_energy_relationship(transition)?;
         
+        Ok(self.success_result(transition, forbidden))
+    }
+    
+    /// Builds the result for a transition that passed every check
+    ///
+    /// A `forbidden` transition has its confidence scaled by the penalty and is noted as such.
+    fn success_result(&self, transition: &QuantumTransition, forbidden: bool) -> ValidationResult {
         // Calculate confidence score
-        let confidence = self.calculate_confidence_score(transition);
+        let mut confidence = self.calculate_confidence_score(transition);
+        let mut notes = Vec::new();
+        if forbidden {
+            confidence *= FORBIDDEN_TRANSITION_PENALTY;
+            notes.push(FORBIDDEN_TRANSITION_NOTE.to_string());
+        }
         
-        Ok(ValidationResult {
+        ValidationResult {
             is_valid: true,
             error_message: None,
-            confidence_score: confidence,
+            confidence_score: self.confidence_precision.round(confidence),
             validator_consensus: vec!["quantum_validator".to_string()],
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
-        })
+            timestamp: self.clock.now_secs(),
+            failed_check: None,
+            notes,
+        }
+    }
+    
+    /// Validates a transition while recording every sub-check in the order it ran
+    ///
+    /// Each event captures the check's inputs, computed value, threshold, and
+    /// outcome; the trace ends at the first failing check. A forbidden transition
+    /// accepted with a penalty is recorded as passed and penalized. The cache is bypassed
+    /// so that the checks always run.
+    pub fn validate_transition_traced(
+        &self,
+        transition: &QuantumTransition,
+    ) -> (Result<ValidationResult, QuantumValidationError>, Vec<TraceEvent>) {
+        let mut trace = Vec::new();
+        let result = self.run_traced_checks(transition, &mut trace);
+        (result, trace)
+    }
+    
+    /// Runs the validation pipeline, appending one event per sub-check to `trace`
+    fn run_traced_checks(
+        &self,
+        transition: &QuantumTransition,
+        trace: &mut Vec<TraceEvent>,
+    ) -> Result<ValidationResult, QuantumValidationError> {
+        let allow_forbidden = self.allow_forbidden_with_penalty;
+        let mut record = |check: FailedCheck,
+                          inputs: &[(&str, f64)],
+                          value,
+                          threshold,
+                          passed: bool| {
+            // Only the selection rules can be waived, and only for a penalty
+            let penalized = !passed && allow_forbidden && check == FailedCheck::SelectionRules;
+            trace.push(TraceEvent {
+                check,
+                inputs: inputs.iter().map(|(name, value)| (name.to_string(), *value)).collect(),
+                value,
+                threshold,
+                passed: passed || penalized,
+                penalized,
+            });
+        };
+        let levels = [
+            ("initial_energy", transition.initial_energy),
+            ("final_energy", transition.final_energy),
+        ];
+        let energy_diff = (transition.final_energy - transition.initial_energy).abs();
+        
+        let outcome = self.validate_finite_inputs(transition);
+        let non_finite = Self::numeric_fields(transition)
+            .iter()
+            .filter(|(_, value)| value.is_some_and(|value| !value.is_finite()))
+            .count();
+        record(FailedCheck::NonFiniteInput, &[], non_finite as f64, 0.0, outcome.is_ok());
+        outcome?;
+        
+        if let Some(mass) = transition.nuclear_mass_kg {
+            let outcome = self.validate_nuclear_mass(transition);
+            let inputs = [("nuclear_mass_kg", mass)];
+            record(FailedCheck::NuclearMass, &inputs, mass, 0.0, outcome.is_ok());
+            outcome?;
+        }
+        
+        let uncertainties: Vec<(&str, f64)> = [
+            ("energy_uncertainty", transition.energy_uncertainty),
+            ("frequency_uncertainty", transition.frequency_uncertainty),
+        ]
+        .into_iter()
+        .filter_map(|(field, value)| value.map(|value| (field, value)))
+        .collect();
+        if !uncertainties.is_empty() {
+            let outcome = self.validate_uncertainties(transition);
+            let smallest = uncertainties
+                .iter()
+                .map(|(_, value)| *value)
+                .fold(f64::INFINITY, f64::min);
+            let passed = outcome.is_ok();
+            record(FailedCheck::PhysicalConstraints, &uncertainties, smallest, 0.0, passed);
+            outcome?;
+        }
+        
+        let outcome = self.validate_energy_conservation(transition);
+        record(
+            FailedCheck::EnergyConservation,
+            &[levels[0], levels[1], ("photon_energy", transition.photon_energy)],
+            (transition.photon_energy - energy_diff).abs(),
+            self.energy_threshold(transition.energy_uncertainty),
+            outcome.is_ok(),
+        );
+        outcome?;
+        
+        let outcome = self.validate_energy_levels(transition);
+        let lowest_level = transition.initial_energy.min(transition.final_energy);
+        record(FailedCheck::InvalidEnergyLevel, &levels, lowest_level, 0.0, outcome.is_ok());
+        outcome?;
+        
+        let outcome = self.validate_selection_rules_penalized(transition);
+        let orbital: Vec<(&str, f64)> = transition
+            .orbital_angular_momentum
+            .map(|(l_initial, l_final)| {
+                vec![("l_initial", l_initial as f64), ("l_final", l_final as f64)]
+            })
+            .unwrap_or_default();
+        record(
+            FailedCheck::SelectionRules,
+            &orbital,
+            Self::orbital_change(transition),
+            1.0,
+            matches!(outcome, Ok(false)),
+        );
+        let forbidden = outcome?;
+        
+        let outcome = self.validate_direction(transition);
+        let energy_change = transition.final_energy - transition.initial_energy;
+        record(FailedCheck::TransitionDirection, &levels, energy_change, 0.0, outcome.is_ok());
+        outcome?;
+        
+        let outcome = self.validate_frequency_energy_relationship(transition);
+        let expected_frequency = self.expected_frequency(transition);
+        let frequency_passed = !matches!(
+            outcome,
+            Err(QuantumValidationError::FrequencyMismatch { .. } |
+                QuantumValidationError::InvalidSourceVelocity { .. }),
+        );
+        record(
+            FailedCheck::FrequencyMismatch,
+            &[("photon_energy", transition.photon_energy), ("frequency", transition.frequency)],
+            (transition.frequency - expected_frequency).abs(),
+            self.frequency_threshold(transition, expected_frequency),
+            frequency_passed,
+        );
+        if frequency_passed {
+            let expected_wavelength = self.expected_wavelength(transition);
+            record(
+                FailedCheck::WavelengthMismatch,
+                &[("frequency", transition.frequency), ("wavelength", transition.wavelength)],
+                (transition.wavelength - expected_wavelength).abs(),
+                expected_wavelength * self.tolerances.wavelength,
+                outcome.is_ok(),
+            );
+        }
+        outcome?;
+        
+        Ok(self.success_result(transition, forbidden))
+    }
+    
+    /// Validates that timestamps strictly increase along a sequence of transitions
+    pub fn validate_temporal_ordering(
+        &self,
+        transitions: &[QuantumTransition],
+    ) -> Result<(), QuantumValidationError> {
+        for (index, pair) in transitions.windows(2).enumerate() {
+            if pair[1].timestamp <= pair[0].timestamp {
+                return Err(QuantumValidationError::NonMonotonicTimestamp {
+                    index: index + 1,
+                    prev: pair[0].timestamp,
+                    curr: pair[1].timestamp,
+                });
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Validates that all numeric fields are finite, then the nuclear mass and uncertainties
+    fn validate_inputs(
+        &self,
+        transition: &QuantumTransition,
+    ) -> Result<(), QuantumValidationError> {
+        self.validate_finite_inputs(transition)?;
+        self.validate_nuclear_mass(transition)?;
+        self.validate_uncertainties(transition)
+    }
+    
+    /// Every numeric field of a transition, with optional fields `None` when absent
+    fn numeric_fields(transition: &QuantumTransition) -> [(&'static str, Option<f64>); 9] {
+        [
+            ("initial_energy", Some(transition.initial_energy)),
+            ("final_energy", Some(transition.final_energy)),
+            ("photon_energy", Some(transition.photon_energy)),
+            ("wavelength", Some(transition.wavelength)),
+            ("frequency", Some(transition.frequency)),
+            ("source_velocity_ms", transition.source_velocity_ms),
+            ("nuclear_mass_kg", transition.nuclear_mass_kg),
+            ("energy_uncertainty", transition.energy_uncertainty),
+            ("frequency_uncertainty", transition.frequency_uncertainty),
+        ]
+    }
+    
+    /// Validates that all numeric fields are finite
+    fn validate_finite_inputs(
+        &self,
+        transition: &QuantumTransition,
+    ) -> Result<(), QuantumValidationError> {
+        for (field, value) in Self::numeric_fields(transition) {
+            if value.is_some_and(|value| !value.is_finite()) {
+                return Err(QuantumValidationError::NonFiniteInput { field });
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Validates that a given nuclear mass is positive
+    fn validate_nuclear_mass(
+        &self,
+        transition: &QuantumTransition,
+    ) -> Result<(), QuantumValidationError> {
+        match transition.nuclear_mass_kg {
+            Some(mass) if mass <= 0.0 => Err(QuantumValidationError::InvalidNuclearMass { mass }),
+            _ => Ok(()),
+        }
+    }
+    
+    /// Validates that given measurement uncertainties are non-negative
+    fn validate_uncertainties(
+        &self,
+        transition: &QuantumTransition,
+    ) -> Result<(), QuantumValidationError> {
+        let uncertainties = [
+            ("energy_uncertainty", transition.energy_uncertainty),
+            ("frequency_uncertainty", transition.frequency_uncertainty),
+        ];
+        
+        for (field, value) in uncertainties {
+            if let Some(value) = value.filter(|value| *value < 0.0) {
+                return Err(QuantumValidationError::InvalidUncertainty { field, value });
+            }
+        }
+        
+        Ok(())
     }
     
     /// Validates energy conservation using Planck's equation
@@ -71,7 +653,8 @@ impl QuantumValidator {
         let energy_diff = (transition.final_energy - transition.initial_energy).abs();
         let photon_energy = transition.photon_energy;
         
-        if (photon_energy - energy_diff).abs() > self.tolerance {
+        let threshold = self.energy_threshold(transition.energy_uncertainty);
+        if (photon_energy - energy_diff).abs() > threshold {
             return Err(QuantumValidationError::EnergyConservationViolation {
                 expected: energy_diff,
                 actual: photon_energy,
@@ -81,120 +664,716 @@ impl QuantumValidator {
         Ok(())
     }
     
-    /// Validates quantum mechanical selection rules
-    fn validate_selection_rules(
+    /// Validates energy conservation with the leading relativistic correction for nuclear charge `z`
+    ///
+    /// Transitions not flagged as `relativistic` are checked non-relativistically.
+    pub fn validate_relativistic(
         &self,
         transition: &QuantumTransition,
+        z: u32,
     ) -> Result<(), QuantumValidationError> {
-        // Simplified selection rules validation
-        // In a real implementation, this would check orbital angular momentum,
-        // total angular momentum, and magnetic quantum number changes
-        
-        if transition.initial_energy <= 0.0 || transition.final_energy <= 0.0 {
-            return Err(QuantumValidationError::SelectionRulesViolation);
+        if !transition.relativistic {
+            return self.validate_energy_conservation(transition);
         }
         
-        // Check if transition is physically allowed
-        if transition.initial_energy == transition.final_energy {
-            return Err(QuantumValidationError::SelectionRulesViolation);
+        let rydberg = self.rydberg_energy(transition)?;
+        let initial_level = self.relativistic_binding_energy(transition.initial_energy, z, rydberg)?;
+        let final_level = self.relativistic_binding_energy(transition.final_energy, z, rydberg)?;
+        let expected = (final_level - initial_level).abs();
+        
+        let threshold = self.energy_threshold(transition.energy_uncertainty);
+        if (transition.photon_energy - expected).abs() > threshold {
+            return Err(QuantumValidationError::EnergyConservationViolation {
+                expected,
+                actual: transition.photon_energy,
+            });
         }
         
         Ok(())
     }
     
-    /// Validates the relationship between frequency and energy
-    fn validate_frequency_energy_relationship(
+    /// Applies the leading fine-structure correction to a hydrogenic binding energy in eV
+    ///
+    /// B' = B[1 + (Zα)²/n² (n/(j+½) − ¾)] for j = ½, where the correction is largest,
+    /// with the principal quantum number inferred from B = Z²Ry/n².
+    fn relativistic_binding_energy(
+        &self,
+        binding_energy: f64,
+        z: u32,
+        rydberg: f64,
+    ) -> Result<f64, QuantumValidationError> {
+        if binding_energy <= 0.0 || z == 0 {
+            return Err(QuantumValidationError::InvalidEnergyLevel { level: binding_energy });
+        }
+        
+        let alpha = self.constants.fine_structure_constant;
+        let z_alpha = z as f64 * alpha;
+        let n = z as f64 * (rydberg / binding_energy).sqrt();
+        
+        Ok(binding_energy * (1.0 + z_alpha.powi(2) / n.powi(2) * (n - 0.75)))
+    }
+    
+    /// Electron–nucleus reduced mass µ = m_e·M/(m_e + M) in kg
+    ///
+    /// Without a nuclear mass the nucleus is treated as infinitely heavy and µ = m_e.
+    pub fn reduced_mass(&self, transition: &QuantumTransition) -> Result<f64, QuantumValidationError> {
+        let electron_mass = self.constants.electron_mass;
+        
+        match transition.nuclear_mass_kg {
+            None => Ok(electron_mass),
+            Some(mass) if mass.is_finite() && mass > 0.0 => {
+                Ok(electron_mass * mass / (electron_mass + mass))
+            }
+            Some(mass) => Err(QuantumValidationError::InvalidNuclearMass { mass }),
+        }
+    }
+    
+    /// Rydberg energy in eV for the transition's reduced mass, Ry = µc²α²/2
+    fn rydberg_energy(&self, transition: &QuantumTransition) -> Result<f64, QuantumValidationError> {
+        let rest_energy = self.reduced_mass(transition)? *
+            self.constants.speed_of_light.powi(2) / self.constants.electron_charge; // eV
+        
+        Ok(rest_energy * self.constants.fine_structure_constant.powi(2) / 2.0)
+    }
+    
+    /// Validates the photon energy against the Bohr formula for a hydrogenic ion of charge `z`
+    ///
+    /// The expected energy Z²Ry(1/n_f² − 1/n_i²) uses the reduced-mass Rydberg, so
+    /// isotopes such as deuterium are checked against their own shifted lines.
+    pub fn validate_hydrogenic_transition(
         &self,
         transition: &QuantumTransition,
+        z: u32,
+        n_initial: u32,
+        n_final: u32,
     ) -> Result<(), QuantumValidationError> {
-        // E = hf, so f = E/h
-        let expected_frequency = transition.photon_energy * 
-            PhysicalConstants::ELECTRON_CHARGE / PhysicalConstants::PLANCK_CONSTANT;
+        for n in [n_initial, n_final] {
+            if n == 0 {
+                return Err(QuantumValidationError::InvalidPrincipalQuantumNumber { n });
+            }
+        }
         
-        let frequency_diff = (transition.frequency - expected_frequency).abs();
-        let frequency_tolerance = expected_frequency * 1e-10; // 0.00000001% tolerance
+        let rydberg = self.rydberg_energy(transition)?;
+        let term = |n: u32| 1.0 / (n as f64).powi(2);
+        let expected = (z as f64).powi(2) * rydberg * (term(n_final) - term(n_initial)).abs();
         
-        if frequency_diff > frequency_tolerance {
-            return Err(QuantumValidationError::FrequencyCalculationError);
+        let threshold = self.energy_threshold(transition.energy_uncertainty);
+        if (transition.photon_energy - expected).abs() > threshold {
+            return Err(QuantumValidationError::EnergyConservationViolation {
+                expected,
+                actual: transition.photon_energy,
+            });
         }
         
-        // Validate wavelength-frequency relationship: c = λf
-        let expected_wavelength = PhysicalConstants::SPEED_OF_LIGHT / transition.frequency * 1e9; // nm
-        let wavelength_diff = (transition.wavelength - expected_wavelength).abs();
-        let wavelength_tolerance = expected_wavelength * 1e-10;
+        Ok(())
+    }
+    
+    /// Validates that an ionizing event conserves charge
+    ///
+    /// Each emitted electron raises the charge of the remaining ion by one, so the
+    /// final charge must equal the initial charge plus the number of electrons emitted.
+    pub fn validate_charge_conservation(
+        &self,
+        initial_charge: i32,
+        final_charge: i32,
+        emitted_electrons: u32,
+    ) -> Result<(), QuantumValidationError> {
+        let expected = initial_charge as i64 + emitted_electrons as i64;
+        let actual = final_charge as i64;
         
-        if wavelength_diff > wavelength_tolerance {
-            return Err(QuantumValidationError::FrequencyCalculationError);
+        if actual != expected {
+            return Err(QuantumValidationError::ChargeNotConserved { expected, actual });
         }
         
         Ok(())
     }
     
-    /// Calculates confidence score for the validation
-    fn calculate_confidence_score(&self, transition: &QuantumTransition) -> f64 {
-        let mut score = 1.0;
+    /// Validates the photon energies of a Compton scattering event
+    ///
+    /// The scattered wavelength must exceed the incident one by the Compton shift
+    /// Δλ = (h/m_e c)(1 − cos θ), within the relative wavelength tolerance.
+    pub fn validate_compton(
+        &self,
+        incident_energy_ev: f64,
+        scattered_energy_ev: f64,
+        scattering_angle_rad: f64,
+    ) -> Result<(), QuantumValidationError> {
+        let fields = [
+            ("incident_energy_ev", incident_energy_ev),
+            ("scattered_energy_ev", scattered_energy_ev),
+            ("scattering_angle_rad", scattering_angle_rad),
+        ];
         
-        // Reduce score based on energy precision
-        let energy_diff = (transition.final_energy - transition.initial_energy).abs();
-        let photon_energy_error = (transition.photon_energy - energy_diff).abs();
-        let relative_error = photon_energy_error / energy_diff;
+        for (field, value) in fields {
+            if !value.is_finite() {
+                return Err(QuantumValidationError::NonFiniteInput { field });
+            }
+        }
         
-        score *= (1.0 - relative_error.min(0.1) * 10.0);
+        for energy in [incident_energy_ev, scattered_energy_ev] {
+            if energy <= 0.0 {
+                return Err(QuantumValidationError::InvalidEnergyLevel { level: energy });
+            }
+        }
         
-        // Boost score for common transitions
-        if self.is_common_transition(transition) {
-            score *= 1.1;
+        let hc = self.constants.planck_constant * self.constants.speed_of_light /
+            self.constants.electron_charge; // eV⋅m
+        let compton_wavelength = self.constants.planck_constant /
+            (self.constants.electron_mass * self.constants.speed_of_light);
+        
+        let incident_wavelength = hc / incident_energy_ev;
+        let scattered_wavelength = hc / scattered_energy_ev;
+        let expected_shift = compton_wavelength * (1.0 - scattering_angle_rad.cos());
+        let actual_shift = scattered_wavelength - incident_wavelength;
+        
+        let expected_wavelength = incident_wavelength + expected_shift;
+        if (scattered_wavelength - expected_wavelength).abs() >
+            expected_wavelength * self.tolerances.wavelength
+        {
+            return Err(QuantumValidationError::ComptonKinematicsViolation {
+                expected_shift,
+                actual_shift,
+            });
         }
         
-        score.min(1.0).max(0.0)
+        Ok(())
     }
     
-    /// Checks if this is a common, well-known transition
-    fn is_common_transition(&self, transition: &QuantumTransition) -> bool {
-        // Check for hydrogen Lyman, Balmer, or Paschen series
-        let hydrogen_transitions = [
-            (13.6, 3.4),   // n=1 to n=2 (Lyman alpha)
-            (13.6, 1.51),  // n=1 to n=3 (Lyman beta)
-            (3.4, 1.51),   // n=2 to n=3 (Balmer alpha)
+    /// Validates a rovibrational transition against the rigid-rotor/harmonic-oscillator model
+    ///
+    /// Requires ΔJ = ±1 and a photon energy matching the difference of
+    /// E(v, J) = hν(v + ½) + B·J(J + 1) between the two levels, within the same
+    /// uncertainty-widened band as atomic transitions.
+    pub fn validate_rovibrational(
+        &self,
+        transition: &MolecularTransition,
+    ) -> Result<(), QuantumValidationError> {
+        let fields = [
+            ("harmonic_frequency", transition.harmonic_frequency),
+            ("rotational_constant", transition.rotational_constant),
+            ("photon_energy", transition.photon_energy),
         ];
         
-        for (initial, final) in hydrogen_transitions.iter() {
-            if (transition.initial_energy - initial).abs() < 0.1 &&
-               (transition.final_energy - final).abs() < 0.1 {
-                return true;
+        for (field, value) in fields {
+            if !value.is_finite() {
+                return Err(QuantumValidationError::NonFiniteInput { field });
+            }
+        }
+        
+        if let Some(value) = transition.energy_uncertainty {
+            let field = "energy_uncertainty";
+            if !value.is_finite() {
+                return Err(QuantumValidationError::NonFiniteInput { field });
             }
+            if value < 0.0 {
+                return Err(QuantumValidationError::InvalidUncertainty { field, value });
+            }
+        }
+        
+        if transition.harmonic_frequency <= 0.0 || transition.rotational_constant <= 0.0 {
+            return Err(QuantumValidationError::InvalidMolecularConstants {
+                harmonic_frequency: transition.harmonic_frequency,
+                rotational_constant: transition.rotational_constant,
+            });
+        }
+        
+        if transition.j_initial.abs_diff(transition.j_final) != 1 {
+            return Err(QuantumValidationError::RotationalSelectionRuleViolation {
+                j_initial: transition.j_initial,
+                j_final: transition.j_final,
+            });
+        }
+        
+        let initial_level =
+            self.rovibrational_energy(transition, transition.v_initial, transition.j_initial);
+        let final_level =
+            self.rovibrational_energy(transition, transition.v_final, transition.j_final);
+        let expected = (final_level - initial_level).abs();
+        
+        let threshold = self.energy_threshold(transition.energy_uncertainty);
+        if (transition.photon_energy - expected).abs() > threshold {
+            return Err(QuantumValidationError::EnergyConservationViolation {
+                expected,
+                actual: transition.photon_energy,
+            });
         }
         
-        false
+        Ok(())
     }
     
-    /// Batch validate multiple transitions
-    pub fn batch_validate_transitions(
-        &self,
-        transitions: &[QuantumTransition],
-    ) -> Vec<ValidationResult> {
-        transitions
-            .iter()
-            .map(|transition| {
-                self.validate_transition(transition)
-                    .unwrap_or_else(|error| ValidationResult {
-                        is_valid: false,
-                        error_message: Some(error.to_string()),
-                        confidence_score: 0.0,
-                        validator_consensus: vec![],
-                        timestamp: std::time::SystemTime::now()
-                            .duration_since(std::time::UNIX_EPOCH)
-                            .unwrap()
-                            .as_secs(),
-                    })
-            })
-            .collect()
+    /// Energy in eV of the rovibrational level (v, J) of a molecule
+    fn rovibrational_energy(&self, transition: &MolecularTransition, v: u32, j: u32) -> f64 {
+        let vibrational_quantum = self.constants.planck_constant * transition.harmonic_frequency /
+            self.constants.electron_charge; // eV
+        let j = j as f64;
+        
+        vibrational_quantum * (v as f64 + 0.5) + transition.rotational_constant * j * (j + 1.0)
     }
-}
-
-#[cfg(test)]
-mod tests {
+    
+    /// Validates that two lines form a spin-orbit doublet with the expected splitting
+    pub fn validate_fine_structure_doublet(
+        &self,
+        line1: &QuantumTransition,
+        line2: &QuantumTransition,
+        expected_splitting_ev: f64,
+    ) -> Result<(), QuantumValidationError> {
+        let actual = (line1.photon_energy - line2.photon_energy).abs();
+        
+        if (actual - expected_splitting_ev).abs() > self.tolerances.splitting {
+            return Err(QuantumValidationError::FineStructureMismatch {
+                expected: expected_splitting_ev,
+                actual,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Estimates the relative intensity of a transition at thermal equilibrium
+    ///
+    /// The initial level is populated according to g_i·exp(−E_i/kT) and the rate is
+    /// weighted by the number of final states g_f. Only ratios between transitions
+    /// evaluated at the same temperature are meaningful.
+    pub fn estimate_relative_intensity(
+        &self,
+        transition: &QuantumTransition,
+        g_initial: u32,
+        g_final: u32,
+        temperature_k: f64,
+    ) -> Result<f64, QuantumValidationError> {
+        if g_initial == 0 || g_final == 0 || !(temperature_k > 0.0 && temperature_k.is_finite()) {
+            return Err(QuantumValidationError::InvalidIntensityParameters {
+                g_initial,
+                g_final,
+                temperature: temperature_k,
+            });
+        }
+        
+        let kt = self.constants.boltzmann_constant * temperature_k / self.constants.electron_charge; // eV
+        let population = g_initial as f64 * (-transition.initial_energy / kt).exp();
+        
+        Ok(population * g_final as f64)
+    }
+    
+    /// Validates that both levels are positive binding energies and that they differ
+    fn validate_energy_levels(
+        &self,
+        transition: &QuantumTransition,
+    ) -> Result<(), QuantumValidationError> {
+        for level in [transition.initial_energy, transition.final_energy] {
+            if level <= 0.0 {
+                return Err(QuantumValidationError::InvalidEnergyLevel { level });
+            }
+        }
+        
+        if transition.initial_energy == transition.final_energy {
+            return Err(QuantumValidationError::NoLevelChange { level: transition.initial_energy });
+        }
+        
+        Ok(())
+    }
+    
+    /// Validates quantum mechanical selection rules
+    ///
+    /// Applies the electric dipole rule Δl = ±1 when the transition carries
+    /// orbital quantum numbers; transitions without them are not checked.
+    fn validate_selection_rules(
+        &self,
+        transition: &QuantumTransition,
+    ) -> Result<(), QuantumValidationError> {
+        match transition.orbital_angular_momentum {
+            Some((l_initial, l_final)) if l_initial.abs_diff(l_final) != 1 => {
+                Err(QuantumValidationError::SelectionRulesViolation)
+            }
+            _ => Ok(()),
+        }
+    }
+    
+    /// |Δl| between the levels, or 1 (dipole-allowed) when no orbital quantum numbers are given
+    fn orbital_change(transition: &QuantumTransition) -> f64 {
+        transition
+            .orbital_angular_momentum
+            .map_or(1.0, |(l_initial, l_final)| l_initial.abs_diff(l_final) as f64)
+    }
+    
+    /// Validates the selection rules, returning whether a violation was accepted with a penalty
+    fn validate_selection_rules_penalized(
+        &self,
+        transition: &QuantumTransition,
+    ) -> Result<bool, QuantumValidationError> {
+        match self.validate_selection_rules(transition) {
+            Err(QuantumValidationError::SelectionRulesViolation)
+                if self.allow_forbidden_with_penalty => Ok(true),
+            outcome => outcome.map(|()| false),
+        }
+    }
+    
+    /// Validates that a declared direction matches the one implied by the energies
+    fn validate_direction(
+        &self,
+        transition: &QuantumTransition,
+    ) -> Result<(), QuantumValidationError> {
+        let declared = match transition.declared_kind {
+            Some(declared) => declared,
+            None => return Ok(()),
+        };
+        
+        let derived = transition.kind();
+        if derived != Some(declared) {
+            return Err(QuantumValidationError::DirectionMismatch { declared, derived });
+        }
+        
+        Ok(())
+    }
+    
+    /// Validates the relationship between frequency and energy
+    fn validate_frequency_energy_relationship(
+        &self,
+        transition: &QuantumTransition,
+    ) -> Result<(), QuantumValidationError> {
+        if let Some(velocity) = transition.source_velocity_ms {
+            if velocity.abs() >= self.constants.speed_of_light {
+                return Err(QuantumValidationError::InvalidSourceVelocity { velocity });
+            }
+        }
+        
+        let expected_frequency = self.expected_frequency(transition);
+        
+        let frequency_diff = (transition.frequency - expected_frequency).abs();
+        
+        if frequency_diff > self.frequency_threshold(transition, expected_frequency) {
+            return Err(QuantumValidationError::FrequencyMismatch {
+                expected: expected_frequency,
+                actual: transition.frequency,
+            });
+        }
+        
+        // Validate wavelength-frequency relationship: c = λf
+        let expected_wavelength = self.expected_wavelength(transition);
+        let wavelength_diff = (transition.wavelength - expected_wavelength).abs();
+        let wavelength_tolerance = expected_wavelength * self.tolerances.wavelength;
+        
+        if wavelength_diff > wavelength_tolerance {
+            return Err(QuantumValidationError::WavelengthMismatch {
+                expected: expected_wavelength,
+                actual: transition.wavelength,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Observed frequency in Hz implied by the rest-frame photon energy, f = E/h
+    ///
+    /// For a moving source the relativistic Doppler factor √((1 − β)/(1 + β)) is
+    /// applied, with β > 0 for a receding source.
+    fn expected_frequency(&self, transition: &QuantumTransition) -> f64 {
+        let rest_frequency = transition.photon_energy *
+            self.constants.electron_charge / self.constants.planck_constant;
+        
+        match transition.source_velocity_ms {
+            Some(velocity) => {
+                let beta = velocity / self.constants.speed_of_light;
+                rest_frequency * ((1.0 - beta) / (1.0 + beta)).sqrt()
+            }
+            None => rest_frequency,
+        }
+    }
+    
+    /// Wavelength in nm implied by the stated frequency, λ = c/f
+    fn expected_wavelength(&self, transition: &QuantumTransition) -> f64 {
+        self.constants.speed_of_light / transition.frequency * 1e9
+    }
+    
+    /// Largest allowed photon energy discrepancy in eV
+    ///
+    /// With a measurement uncertainty σ the tolerance and σ add in quadrature,
+    /// and discrepancies up to `coverage` times the combined bar are accepted.
+    fn energy_threshold(&self, energy_uncertainty: Option<f64>) -> f64 {
+        match energy_uncertainty {
+            Some(sigma) => self.tolerances.coverage * sigma.hypot(self.tolerances.energy),
+            None => self.tolerances.energy,
+        }
+    }
+    
+    /// Largest allowed frequency discrepancy in Hz, combined with any measurement uncertainty
+    fn frequency_threshold(&self, transition: &QuantumTransition, expected_frequency: f64) -> f64 {
+        let tolerance = expected_frequency * self.tolerances.frequency;
+        match transition.frequency_uncertainty {
+            Some(sigma) => self.tolerances.coverage * sigma.hypot(tolerance),
+            None => tolerance,
+        }
+    }
+    
+    /// Number of combined standard deviations separating the measured and expected values
+    ///
+    /// Takes the larger of the energy and frequency discrepancies; `None` when the
+    /// transition carries no measurement uncertainties.
+    fn sigma_level(&self, transition: &QuantumTransition) -> Option<f64> {
+        let energy_diff = (transition.final_energy - transition.initial_energy).abs();
+        let energy_sigma = transition.energy_uncertainty.map(|sigma| {
+            (transition.photon_energy - energy_diff).abs() / sigma.hypot(self.tolerances.energy)
+        });
+        
+        let expected_frequency = self.expected_frequency(transition);
+        let frequency_sigma = transition.frequency_uncertainty.map(|sigma| {
+            (transition.frequency - expected_frequency).abs() /
+                sigma.hypot(expected_frequency * self.tolerances.frequency)
+        });
+        
+        match (energy_sigma, frequency_sigma) {
+            (Some(energy), Some(frequency)) => Some(energy.max(frequency)),
+            (level, None) | (None, level) => level,
+        }
+    }
+    
+    /// Reports the computed value and threshold of every check, even for valid transitions
+    pub fn explain(&self, transition: &QuantumTransition) -> ValidationExplanation {
+        let energy_diff = (transition.final_energy - transition.initial_energy).abs();
+        let expected_frequency = self.expected_frequency(transition);
+        let expected_wavelength = self.expected_wavelength(transition);
+        let lowest_level = transition.initial_energy.min(transition.final_energy);
+        
+        let explain = |check, value: f64, threshold: f64| CheckExplanation {
+            check,
+            value,
+            threshold,
+            passed: value <= threshold,
+        };
+        
+        let mut energy_levels = explain(FailedCheck::InvalidEnergyLevel, lowest_level, 0.0);
+        energy_levels.passed = lowest_level > 0.0 && energy_diff > 0.0;
+        
+        let delta_l = Self::orbital_change(transition);
+        let mut selection_rules = explain(FailedCheck::SelectionRules, delta_l, 1.0);
+        selection_rules.passed = delta_l == 1.0 || self.allow_forbidden_with_penalty;
+        
+        ValidationExplanation {
+            checks: vec![
+                explain(
+                    FailedCheck::EnergyConservation,
+                    (transition.photon_energy - energy_diff).abs(),
+                    self.energy_threshold(transition.energy_uncertainty),
+                ),
+                energy_levels,
+                selection_rules,
+                explain(
+                    FailedCheck::FrequencyMismatch,
+                    (transition.frequency - expected_frequency).abs(),
+                    self.frequency_threshold(transition, expected_frequency),
+                ),
+                explain(
+                    FailedCheck::WavelengthMismatch,
+                    (transition.wavelength - expected_wavelength).abs(),
+                    expected_wavelength * self.tolerances.wavelength,
+                ),
+            ],
+        }
+    }
+    
+    /// Calculates confidence score for the validation
+    fn calculate_confidence_score(&self, transition: &QuantumTransition) -> f64 {
+        let mut score = 1.0;
+        
+        // Reduce score based on energy precision
+        let energy_diff = (transition.final_energy - transition.initial_energy).abs();
+        let photon_energy_error = (transition.photon_energy - energy_diff).abs();
+        let relative_error = photon_energy_error / energy_diff;
+        
+        score *= (1.0 - relative_error.min(0.1) * 10.0);
+        
+        // Reduce score for discrepancies further out in the uncertainty band
+        if let Some(sigma_level) = self.sigma_level(transition) {
+            score *= 1.0 - (sigma_level / self.tolerances.coverage).min(1.0) * 0.5;
+        }
+        
+        // Boost score for common transitions
+        if self.is_common_transition(transition) {
+            score *= 1.1;
+        }
+        
+        score.min(1.0).max(0.0)
+    }
+    
+    /// Checks if this is a common, well-known transition from the known-transition table
+    fn is_common_transition(&self, transition: &QuantumTransition) -> bool {
+        self.known_transitions.iter().any(|known| {
+            (transition.initial_energy - known.initial_energy).abs() < known.tolerance &&
+                (transition.final_energy - known.final_energy).abs() < known.tolerance
+        })
+    }
+    
+    /// Batch validate multiple transitions
+    pub fn batch_validate_transitions(
+        &self,
+        transitions: &[QuantumTransition],
+    ) -> Vec<ValidationResult> {
+        self.validate_transitions_iter(transitions).collect()
+    }
+    
+    /// Batch validate transitions, validating each distinct transition only once
+    ///
+    /// Duplicates receive a copy of the first result, so the output lines up
+    /// positionally with the input, and the failure observer sees every failed
+    /// position, duplicates included.
+    pub fn batch_validate_transitions_dedup(
+        &self,
+        transitions: &[QuantumTransition],
+    ) -> Vec<ValidationResult> {
+        let mut unique_results: Vec<ValidationResult> = Vec::new();
+        let mut seen: HashMap<&QuantumTransition, usize> = HashMap::new();
+        let mut positions = Vec::with_capacity(transitions.len());
+        
+        for transition in transitions {
+            let index = *seen.entry(transition).or_insert_with(|| {
+                unique_results.push(
+                    self.validate_transition(transition)
+                        .unwrap_or_else(|error| self.failure_result(&error)),
+                );
+                unique_results.len() - 1
+            });
+            positions.push(index);
+        }
+        
+        positions
+            .into_iter()
+            .map(|index| unique_results[index].clone())
+            .inspect(|result| self.notify_failure(result))
+            .collect()
+    }
+    
+    /// Batch validate transitions, also rejecting passes below `min_confidence`
+    ///
+    /// Transitions that satisfy every physics check but score under the threshold
+    /// are reported as failed `LowConfidence` results.
+    pub fn batch_validate_transitions_strict(
+        &self,
+        transitions: &[QuantumTransition],
+        min_confidence: f64,
+    ) -> Result<Vec<ValidationResult>, QuantumValidationError> {
+        if !(0.0..=1.0).contains(&min_confidence) {
+            return Err(QuantumValidationError::InvalidConfidenceThreshold {
+                threshold: min_confidence,
+            });
+        }
+        
+        Ok(transitions
+            .iter()
+            .map(|transition| {
+                self.validate_transition(transition)
+                    .and_then(|result| {
+                        if result.confidence_score < min_confidence {
+                            return Err(QuantumValidationError::InsufficientConfidence {
+                                confidence: result.confidence_score,
+                                threshold: min_confidence,
+                            });
+                        }
+                        Ok(result)
+                    })
+                    .unwrap_or_else(|error| self.failure_result(&error))
+            })
+            .inspect(|result| self.notify_failure(result))
+            .collect())
+    }
+    
+    /// Batch validate transitions, stopping after `max_consecutive_failures` failures in a row
+    ///
+    /// Returns the results processed so far and whether the breaker tripped. A long
+    /// run of failures usually signals a systemic problem with the feed, such as
+    /// wrong units. A limit of zero disables the breaker.
+    pub fn batch_validate_transitions_with_breaker(
+        &self,
+        transitions: &[QuantumTransition],
+        max_consecutive_failures: usize,
+    ) -> (Vec<ValidationResult>, bool) {
+        let mut results = Vec::new();
+        let mut consecutive_failures = 0;
+        
+        for result in self.validate_transitions_iter(transitions) {
+            consecutive_failures = if result.is_valid { 0 } else { consecutive_failures + 1 };
+            results.push(result);
+            
+            if max_consecutive_failures > 0 && consecutive_failures >= max_consecutive_failures {
+                return (results, true);
+            }
+        }
+        
+        (results, false)
+    }
+    
+    /// Lazily validate transitions, yielding one result per input without collecting
+    pub fn validate_transitions_iter<'a, I>(
+        &'a self,
+        transitions: I,
+    ) -> impl Iterator<Item = ValidationResult> + 'a
+    where
+        I: IntoIterator<Item = &'a QuantumTransition>,
+        I::IntoIter: 'a,
+    {
+        transitions
+            .into_iter()
+            .map(move |transition| {
+                self.validate_transition(transition)
+                    .unwrap_or_else(|error| self.failure_result(&error))
+            })
+            .inspect(move |result| self.notify_failure(result))
+    }
+    
+    /// Builds the failed result reported for `error` in batch validation
+    fn failure_result(&self, error: &QuantumValidationError) -> ValidationResult {
+        ValidationResult {
+            is_valid: false,
+            error_message: Some(error.to_string()),
+            confidence_score: 0.0,
+            validator_consensus: vec![],
+            timestamp: self.clock.now_secs(),
+            failed_check: Some(error.failed_check()),
+            notes: Vec::new(),
+        }
+    }
+    
+    /// Passes a failed result to the registered observer, if any
+    fn notify_failure(&self, result: &ValidationResult) {
+        if result.is_valid {
+            return;
+        }
+        
+        if let Some(observer) = &self.on_failure {
+            observer(result);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
     use super::*;
+    use crate::types::{FixedClock, PhysicalConstants, WavelengthUnit};
+    use proptest::prelude::*;
+    
+    fn consistent_transition(initial_energy: f64, final_energy: f64) -> QuantumTransition {
+        let photon_energy = (initial_energy - final_energy).abs();
+        let frequency = photon_energy * PhysicalConstants::ELECTRON_CHARGE /
+            PhysicalConstants::PLANCK_CONSTANT;
+        
+        QuantumTransition {
+            electron_id: "test".to_string(),
+            initial_energy,
+            final_energy,
+            photon_energy,
+            wavelength: PhysicalConstants::SPEED_OF_LIGHT / frequency * 1e9,
+            frequency,
+            timestamp: 1640995200,
+            validated: false,
+            validator_id: "test".to_string(),
+            relativistic: false,
+            declared_kind: None,
+            source_velocity_ms: None,
+            energy_uncertainty: None,
+            frequency_uncertainty: None,
+            nuclear_mass_kg: None,
+            orbital_angular_momentum: None,
+        }
+    }
     
     #[test]
     fn test_valid_hydrogen_transition() {
@@ -209,6 +1388,13 @@ mod tests {
             timestamp: 1640995200,
             validated: false,
             validator_id: "test".to_string(),
+            relativistic: false,
+            declared_kind: None,
+            source_velocity_ms: None,
+            energy_uncertainty: None,
+            frequency_uncertainty: None,
+            nuclear_mass_kg: None,
+            orbital_angular_momentum: None,
         };
         
         let result = validator.validate_transition(&transition);
@@ -229,9 +1415,931 @@ mod tests {
             timestamp: 1640995200,
             validated: false,
             validator_id: "test".to_string(),
+            relativistic: false,
+            declared_kind: None,
+            source_velocity_ms: None,
+            energy_uncertainty: None,
+            frequency_uncertainty: None,
+            nuclear_mass_kg: None,
+            orbital_angular_momentum: None,
+        };
+        
+        let result = validator.validate_transition(&transition);
+        assert!(result.is_err());
+    }
+    
+    #[test]
+    fn test_frequency_mismatch() {
+        let validator = QuantumValidator::new();
+        let mut transition = consistent_transition(2.0, 1.0);
+        transition.frequency *= 1.01;
+        
+        let result = validator.validate_transition(&transition);
+        assert!(matches!(result, Err(QuantumValidationError::FrequencyMismatch { .. })));
+    }
+    
+    #[test]
+    fn test_wavelength_mismatch() {
+        let validator = QuantumValidator::new();
+        let mut transition = consistent_transition(2.0, 1.0);
+        transition.wavelength += 10.0;
+        
+        let result = validator.validate_transition(&transition);
+        assert!(matches!(result, Err(QuantumValidationError::WavelengthMismatch { .. })));
+    }
+    
+    #[test]
+    fn test_nan_photon_energy_rejected() {
+        let validator = QuantumValidator::new();
+        let mut transition = consistent_transition(2.0, 1.0);
+        transition.photon_energy = f64::NAN;
+        
+        let result = validator.validate_transition(&transition);
+        assert!(matches!(
+            result,
+            Err(QuantumValidationError::NonFiniteInput { field: "photon_energy" })
+        ));
+    }
+    
+    #[test]
+    fn test_validate_transitions_iter_is_lazy() {
+        let validator = QuantumValidator::new();
+        let valid = consistent_transition(2.0, 1.0);
+        let mut invalid = consistent_transition(2.0, 1.0);
+        invalid.photon_energy = 5.0;
+        
+        // An endless feed can only be consumed lazily
+        let feed = [&valid, &invalid].into_iter().cycle();
+        let failures = validator
+            .validate_transitions_iter(feed)
+            .take(1000)
+            .filter(|result| !result.is_valid)
+            .count();
+        
+        assert_eq!(failures, 500);
+    }
+    
+    #[test]
+    fn test_batch_records_failed_check() {
+        let validator = QuantumValidator::new();
+        let valid = consistent_transition(2.0, 1.0);
+        let mut invalid = consistent_transition(2.0, 1.0);
+        invalid.photon_energy = 5.0;
+        
+        let results = validator.batch_validate_transitions(&[valid, invalid]);
+        assert_eq!(results[0].failed_check, None);
+        assert_eq!(results[1].failed_check, Some(FailedCheck::EnergyConservation));
+    }
+    
+    #[test]
+    fn test_batch_dedup_preserves_positions() {
+        let validator = QuantumValidator::new();
+        let valid = consistent_transition(2.0, 1.0);
+        let mut invalid = consistent_transition(2.0, 1.0);
+        invalid.photon_energy = 5.0;
+        
+        let transitions = vec![
+            valid.clone(),
+            invalid.clone(),
+            valid.clone(),
+            valid.clone(),
+            invalid.clone(),
+        ];
+        
+        let deduped = validator.batch_validate_transitions_dedup(&transitions);
+        let plain = validator.batch_validate_transitions(&transitions);
+        
+        assert_eq!(deduped.len(), transitions.len());
+        for (deduped, plain) in deduped.iter().zip(plain.iter()) {
+            assert_eq!(deduped.is_valid, plain.is_valid);
+            assert_eq!(deduped.error_message, plain.error_message);
+            assert_eq!(deduped.confidence_score, plain.confidence_score);
+        }
+        
+        let validity: Vec<bool> = deduped.iter().map(|result| result.is_valid).collect();
+        assert_eq!(validity, vec![true, false, true, true, false]);
+    }
+    
+    #[test]
+    fn test_relativistic_correction_light_vs_heavy() {
+        let validator = QuantumValidator::new();
+        let rydberg = validator.rydberg_energy(&consistent_transition(2.0, 1.0)).unwrap();
+        
+        // Hydrogen 1s: the correction is ~1e-5 of the binding energy
+        let hydrogen = validator.relativistic_binding_energy(13.6057, 1, rydberg).unwrap();
+        assert!((hydrogen - 13.6057) / 13.6057 < 1e-4);
+        
+        // Hydrogenic gold 1s: the correction is several percent
+        let gold_binding = 79.0_f64.powi(2) * 13.6057;
+        let gold = validator.relativistic_binding_energy(gold_binding, 79, rydberg).unwrap();
+        assert!((gold - gold_binding) / gold_binding > 0.05);
+    }
+    
+    #[test]
+    fn test_validate_relativistic_heavy_element() {
+        let validator = QuantumValidator::new();
+        
+        // Kα-like 2s → 1s transition in hydrogenic gold
+        let gold_1s = 79.0_f64.powi(2) * 13.6057;
+        let gold_2s = gold_1s / 4.0;
+        let mut transition = consistent_transition(gold_2s, gold_1s);
+        let rydberg = validator.rydberg_energy(&transition).unwrap();
+        let expected = validator.relativistic_binding_energy(gold_1s, 79, rydberg).unwrap() -
+            validator.relativistic_binding_energy(gold_2s, 79, rydberg).unwrap();
+        
+        transition.photon_energy = expected;
+        
+        // Rejected without the flag, accepted once marked relativistic
+        assert!(validator.validate_relativistic(&transition, 79).is_err());
+        transition.relativistic = true;
+        assert!(validator.validate_relativistic(&transition, 79).is_ok());
+        
+        // A measurement uncertainty widens the band as in the non-relativistic check
+        transition.photon_energy = expected + 0.01;
+        assert!(validator.validate_relativistic(&transition, 79).is_err());
+        transition.energy_uncertainty = Some(0.01);
+        assert!(validator.validate_relativistic(&transition, 79).is_ok());
+    }
+    
+    proptest! {
+        #[test]
+        fn prop_check_invariants(
+            initial_energy in 0.1f64..100.0,
+            final_energy in 0.1f64..100.0,
+            perturbed_field in 0usize..3,
+        ) {
+            prop_assume!((initial_energy - final_energy).abs() > 1e-3);
+            
+            let transition = consistent_transition(initial_energy, final_energy);
+            prop_assert!(transition.check_invariants().is_ok());
+            
+            let mut perturbed = transition.clone();
+            match perturbed_field {
+                0 => perturbed.frequency *= 1.001,
+                1 => perturbed.wavelength *= 1.001,
+                _ => perturbed.photon_energy = f64::NAN,
+            }
+            prop_assert!(perturbed.check_invariants().is_err());
+        }
+    }
+    
+    #[test]
+    fn test_failure_observer_counts_failures() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        
+        let failures = Arc::new(AtomicUsize::new(0));
+        let observed = Arc::clone(&failures);
+        let validator = QuantumValidator::new().with_failure_observer(move |_| {
+            observed.fetch_add(1, Ordering::SeqCst);
+        });
+        
+        let valid = consistent_transition(2.0, 1.0);
+        let mut invalid = consistent_transition(2.0, 1.0);
+        invalid.photon_energy = 5.0;
+        
+        let transitions = [valid.clone(), invalid.clone(), valid, invalid];
+        validator.batch_validate_transitions(&transitions);
+        assert_eq!(failures.load(Ordering::SeqCst), 2);
+        
+        // Deduplication validates the failing transition once but reports both positions
+        validator.batch_validate_transitions_dedup(&transitions);
+        assert_eq!(failures.load(Ordering::SeqCst), 4);
+    }
+    
+    #[test]
+    fn test_sodium_d_doublet() {
+        let validator = QuantumValidator::new();
+        
+        // Sodium D1 (589.592 nm) and D2 (588.995 nm) are split by ~2.13 meV
+        let d1_energy = crate::utils::wavelength_nm_to_ev(589.592);
+        let d2_energy = crate::utils::wavelength_nm_to_ev(588.995);
+        let d1 = consistent_transition(d1_energy, 2.0 * d1_energy);
+        let d2 = consistent_transition(d2_energy, 2.0 * d2_energy);
+        
+        assert!(validator.validate_fine_structure_doublet(&d1, &d2, 2.131e-3).is_ok());
+        
+        let result = validator.validate_fine_structure_doublet(&d1, &d2, 1e-2);
+        assert!(matches!(
+            result,
+            Err(QuantumValidationError::FineStructureMismatch { .. })
+        ));
+    }
+    
+    #[test]
+    fn test_confidence_precision_is_deterministic() {
+        let precision = ConfidencePrecision { decimal_places: 3 };
+        assert_eq!(precision.round(0.123456789), 0.123);
+        assert_eq!(precision.round(0.9875), 0.988);
+        
+        let validator = QuantumValidator::new().with_confidence_precision(precision);
+        let transition = consistent_transition(3.4, 1.51);
+        
+        let first = validator.validate_transition(&transition).unwrap();
+        let second = validator.validate_transition(&transition).unwrap();
+        assert_eq!(first.confidence_score.to_bits(), second.confidence_score.to_bits());
+        assert_eq!(first.confidence_score, precision.round(first.confidence_score));
+    }
+    
+    #[test]
+    fn test_cache_hit_returns_identical_result() {
+        let validator = QuantumValidator::new().with_cache(8);
+        let transition = consistent_transition(2.0, 1.0);
+        
+        let first = validator.validate_transition(&transition).unwrap();
+        let second = validator.validate_transition(&transition).unwrap();
+        
+        assert_eq!(first.timestamp, second.timestamp);
+        assert_eq!(first.fingerprint_with_timestamp(), second.fingerprint_with_timestamp());
+        assert_eq!(validator.cache.as_ref().unwrap().lock().unwrap().entries.len(), 1);
+    }
+    
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let validator = QuantumValidator::new().with_cache(2);
+        let first = consistent_transition(2.0, 1.0);
+        let second = consistent_transition(3.0, 1.0);
+        let third = consistent_transition(4.0, 1.0);
+        
+        validator.validate_transition(&first).unwrap();
+        validator.validate_transition(&second).unwrap();
+        validator.validate_transition(&first).unwrap();
+        validator.validate_transition(&third).unwrap();
+        
+        let cache = validator.cache.as_ref().unwrap().lock().unwrap();
+        assert!(cache.entries.contains_key(&first));
+        assert!(!cache.entries.contains_key(&second));
+        assert!(cache.entries.contains_key(&third));
+    }
+    
+    #[test]
+    fn test_cache_hits_keep_recency_queue_bounded() {
+        let validator = QuantumValidator::new().with_cache(2);
+        let first = consistent_transition(2.0, 1.0);
+        let second = consistent_transition(3.0, 1.0);
+        let third = consistent_transition(4.0, 1.0);
+        
+        validator.validate_transition(&first).unwrap();
+        validator.validate_transition(&second).unwrap();
+        for _ in 0..100 {
+            validator.validate_transition(&first).unwrap();
+        }
+        assert!(validator.cache.as_ref().unwrap().lock().unwrap().recency.len() <= 4);
+        
+        // The many stale slots for `first` do not make it look old
+        validator.validate_transition(&third).unwrap();
+        let cache = validator.cache.as_ref().unwrap().lock().unwrap();
+        assert!(cache.entries.contains_key(&first));
+        assert!(!cache.entries.contains_key(&second));
+        assert!(cache.entries.contains_key(&third));
+    }
+    
+    #[test]
+    fn test_tolerance_change_bypasses_stale_cache() {
+        let mut validator = QuantumValidator::new().with_cache(8);
+        validator.set_tolerance(1e-3);
+        
+        // Photon energy is 1 µeV off, within the loose tolerance only
+        let mut transition = consistent_transition(2.0, 1.0);
+        transition.photon_energy = 1.000001;
+        transition.frequency = transition.photon_energy * PhysicalConstants::ELECTRON_CHARGE /
+            PhysicalConstants::PLANCK_CONSTANT;
+        transition.wavelength = PhysicalConstants::SPEED_OF_LIGHT / transition.frequency * 1e9;
+        
+        assert!(validator.validate_transition(&transition).is_ok());
+        
+        validator.set_tolerance(1e-15);
+        let result = validator.validate_transition(&transition);
+        assert!(matches!(
+            result,
+            Err(QuantumValidationError::EnergyConservationViolation { .. })
+        ));
+    }
+    
+    #[test]
+    fn test_with_tolerances_discards_cached_results() {
+        let loose = Tolerances { energy: 1e-3, ..Tolerances::default() };
+        let validator = QuantumValidator::new().with_cache(8).with_tolerances(loose);
+        
+        let mut transition = consistent_transition(2.0, 1.0);
+        transition.photon_energy = 1.000001;
+        transition.frequency = transition.photon_energy * PhysicalConstants::ELECTRON_CHARGE /
+            PhysicalConstants::PLANCK_CONSTANT;
+        transition.wavelength = PhysicalConstants::SPEED_OF_LIGHT / transition.frequency * 1e9;
+        assert!(validator.validate_transition(&transition).is_ok());
+        
+        let validator = validator.with_tolerances(Tolerances::default());
+        assert!(validator.cache.as_ref().unwrap().lock().unwrap().entries.is_empty());
+        assert!(matches!(
+            validator.validate_transition(&transition),
+            Err(QuantumValidationError::EnergyConservationViolation { .. })
+        ));
+    }
+    
+    #[test]
+    fn test_with_constants_discards_cached_results() {
+        let validator = QuantumValidator::new().with_cache(8);
+        let transition = consistent_transition(2.0, 1.0);
+        validator.validate_transition(&transition).unwrap();
+        
+        // CODATA 2014 Planck constant, which shifts every expected frequency
+        let codata_2014 = ConstantsSet {
+            codata_version: 2014,
+            planck_constant: 6.626070040e-34,
+            ..ConstantsSet::codata_2018()
         };
+        let validator = validator.with_constants(codata_2014);
+        assert!(validator.cache.as_ref().unwrap().lock().unwrap().entries.is_empty());
+        assert!(matches!(
+            validator.validate_transition(&transition),
+            Err(QuantumValidationError::FrequencyMismatch { .. })
+        ));
+    }
+    
+    #[test]
+    fn test_with_confidence_precision_discards_cached_results() {
+        let validator = QuantumValidator::new().with_cache(8);
+        let transition = consistent_transition(2.0, 1.0);
+        validator.validate_transition(&transition).unwrap();
+        
+        let validator = validator.with_confidence_precision(ConfidencePrecision { decimal_places: 0 });
+        let cached = validator.validate_transition(&transition).unwrap();
+        let fresh = QuantumValidator::new()
+            .with_confidence_precision(ConfidencePrecision { decimal_places: 0 })
+            .validate_transition(&transition)
+            .unwrap();
+        assert_eq!(cached.confidence_score, fresh.confidence_score);
+        assert_eq!(cached.confidence_score, cached.confidence_score.round());
+    }
+    
+    #[test]
+    fn test_emission_and_absorption_directions() {
+        let validator = QuantumValidator::new();
+        
+        let mut emission = consistent_transition(2.0, 1.0);
+        assert_eq!(emission.kind(), Some(TransitionKind::Emission));
+        emission.declared_kind = Some(TransitionKind::Emission);
+        assert!(validator.validate_transition(&emission).is_ok());
+        
+        let mut absorption = consistent_transition(1.0, 2.0);
+        assert_eq!(absorption.kind(), Some(TransitionKind::Absorption));
+        absorption.declared_kind = Some(TransitionKind::Absorption);
+        assert!(validator.validate_transition(&absorption).is_ok());
+        
+        absorption.declared_kind = Some(TransitionKind::Emission);
+        let result = validator.validate_transition(&absorption);
+        assert!(matches!(
+            result,
+            Err(QuantumValidationError::DirectionMismatch {
+                declared: TransitionKind::Emission,
+                derived: Some(TransitionKind::Absorption),
+            })
+        ));
+    }
+    
+    #[test]
+    fn test_validator_uses_configured_constants() {
+        let transition = consistent_transition(2.0, 1.0);
+        assert!(QuantumValidator::new().validate_transition(&transition).is_ok());
+        
+        let mut altered = ConstantsSet::codata_2018();
+        altered.planck_constant *= 1.001;
+        let validator = QuantumValidator::new().with_constants(altered);
+        let result = validator.validate_transition(&transition);
+        assert!(matches!(result, Err(QuantumValidationError::FrequencyMismatch { .. })));
+    }
+    
+    #[test]
+    fn test_lower_level_dominates_at_low_temperature() {
+        let validator = QuantumValidator::new();
+        let from_lower = consistent_transition(1.0, 0.5);
+        let from_upper = consistent_transition(2.0, 0.5);
+        
+        let lower = validator.estimate_relative_intensity(&from_lower, 2, 2, 300.0).unwrap();
+        let upper = validator.estimate_relative_intensity(&from_upper, 2, 2, 300.0).unwrap();
+        assert!(lower > upper * 1e10);
+        
+        // The populations even out as the temperature rises
+        let lower = validator.estimate_relative_intensity(&from_lower, 2, 2, 1e7).unwrap();
+        let upper = validator.estimate_relative_intensity(&from_upper, 2, 2, 1e7).unwrap();
+        assert!((lower / upper - 1.0).abs() < 0.01);
+        
+        assert!(validator.estimate_relative_intensity(&from_lower, 0, 2, 300.0).is_err());
+        assert!(validator.estimate_relative_intensity(&from_lower, 2, 2, 0.0).is_err());
+    }
+    
+    #[test]
+    fn test_explain_passing_transition() {
+        let validator = QuantumValidator::new();
+        let transition = consistent_transition(13.6, 3.4);
+        assert!(validator.validate_transition(&transition).is_ok());
+        
+        let explanation = validator.explain(&transition);
+        assert_eq!(explanation.checks.len(), 5);
+        assert!(explanation.all_passed());
+        for check in &explanation.checks {
+            assert!(check.value.is_finite());
+            assert!(check.threshold.is_finite());
+        }
+        
+        let mut broken = transition.clone();
+        broken.photon_energy += 0.5;
+        let explanation = validator.explain(&broken);
+        assert!(!explanation.checks[0].passed);
+        assert!((explanation.checks[0].value - 0.5).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_custom_tolerances_take_effect() {
+        let mut transition = consistent_transition(2.0, 1.0);
+        transition.photon_energy += 1e-9;
+        transition.frequency = transition.photon_energy *
+            PhysicalConstants::ELECTRON_CHARGE / PhysicalConstants::PLANCK_CONSTANT;
+        transition.wavelength = PhysicalConstants::SPEED_OF_LIGHT / transition.frequency * 1e9;
+        
+        let result = QuantumValidator::new().validate_transition(&transition);
+        assert!(matches!(result, Err(QuantumValidationError::EnergyConservationViolation { .. })));
+        
+        let tolerances = Tolerances { energy: 1e-6, ..Tolerances::default() };
+        let validator = QuantumValidator::new().with_tolerances(tolerances);
+        assert!(validator.validate_transition(&transition).is_ok());
+    }
+    
+    #[test]
+    fn test_redshifted_hydrogen_line() {
+        let validator = QuantumValidator::new();
+        
+        // Balmer alpha from a source receding at 3000 km/s
+        let velocity = 3.0e6;
+        let beta = velocity / PhysicalConstants::SPEED_OF_LIGHT;
+        let mut transition = consistent_transition(3.4, 1.51);
+        transition.frequency *= ((1.0 - beta) / (1.0 + beta)).sqrt();
+        transition.wavelength = PhysicalConstants::SPEED_OF_LIGHT / transition.frequency * 1e9;
         
         let result = validator.validate_transition(&transition);
+        assert!(matches!(result, Err(QuantumValidationError::FrequencyMismatch { .. })));
+        
+        transition.source_velocity_ms = Some(velocity);
+        assert!(validator.validate_transition(&transition).is_ok());
+        
+        // A blueshift of the same speed does not explain the redshifted line
+        transition.source_velocity_ms = Some(-velocity);
+        assert!(validator.validate_transition(&transition).is_err());
+        
+        transition.source_velocity_ms = Some(PhysicalConstants::SPEED_OF_LIGHT);
+        let result = validator.validate_transition(&transition);
+        assert!(matches!(result, Err(QuantumValidationError::InvalidSourceVelocity { .. })));
+    }
+    
+    #[test]
+    fn test_validate_transition_owned_sets_flag() {
+        let validator = QuantumValidator::new();
+        
+        let validated = validator.validate_transition_owned(consistent_transition(2.0, 1.0)).unwrap();
+        assert!(validated.validated);
+        assert_eq!(validated.validator_id, "quantum_validator");
+        
+        let mut invalid = consistent_transition(2.0, 1.0);
+        invalid.photon_energy += 0.1;
+        assert!(validator.validate_transition_owned(invalid.clone()).is_err());
+        assert!(!invalid.validated);
+    }
+    
+    #[test]
+    fn test_wavelength_units_validate_identically() {
+        let validator = QuantumValidator::new();
+        let from_nm = consistent_transition(3.4, 1.51);
+        
+        // The same Balmer alpha line entered from an Ångström dataset
+        let angstroms = from_nm.wavelength * 10.0;
+        let mut from_angstrom = from_nm.clone();
+        from_angstrom.set_wavelength(angstroms, WavelengthUnit::Angstrom);
+        
+        assert!((from_angstrom.wavelength - from_nm.wavelength).abs() < 1e-9);
+        assert!((from_nm.wavelength_in(WavelengthUnit::Angstrom) - angstroms).abs() < 1e-9);
+        assert!((from_nm.wavelength_in(WavelengthUnit::Micrometer) - from_nm.wavelength / 1e3).abs() < 1e-12);
+        
+        let nm_result = validator.validate_transition(&from_nm).unwrap();
+        let angstrom_result = validator.validate_transition(&from_angstrom).unwrap();
+        assert_eq!(nm_result.confidence_score, angstrom_result.confidence_score);
+        
+        // Storing Ångströms as if they were nanometres fails the c = λf check
+        let mut misread = from_nm.clone();
+        misread.wavelength = angstroms;
+        let result = validator.validate_transition(&misread);
+        assert!(matches!(result, Err(QuantumValidationError::WavelengthMismatch { .. })));
+    }
+    
+    #[test]
+    fn test_temporal_ordering() {
+        let validator = QuantumValidator::new();
+        let mut transitions = vec![consistent_transition(2.0, 1.0); 3];
+        for (step, transition) in transitions.iter_mut().enumerate() {
+            transition.timestamp += step as u64;
+        }
+        assert!(validator.validate_temporal_ordering(&transitions).is_ok());
+        
+        transitions[1].timestamp = transitions[0].timestamp;
+        let result = validator.validate_temporal_ordering(&transitions);
+        assert!(matches!(
+            result,
+            Err(QuantumValidationError::NonMonotonicTimestamp { index: 1, .. }),
+        ));
+    }
+    
+    #[test]
+    fn test_uncertainty_band_accepts_noisy_measurement() {
+        let validator = QuantumValidator::new();
+        
+        // Measured photon energy 3 meV high with a 2 meV standard uncertainty: 1.5σ
+        let mut transition = consistent_transition(3.4, 1.51);
+        transition.photon_energy += 0.003;
+        transition.frequency = transition.photon_energy *
+            PhysicalConstants::ELECTRON_CHARGE / PhysicalConstants::PLANCK_CONSTANT;
+        transition.wavelength = PhysicalConstants::SPEED_OF_LIGHT / transition.frequency * 1e9;
+        
+        let result = validator.validate_transition(&transition);
+        assert!(matches!(result, Err(QuantumValidationError::EnergyConservationViolation { .. })));
+        
+        transition.energy_uncertainty = Some(0.002);
+        let noisy = validator.validate_transition(&transition).unwrap();
+        let exact = validator.validate_transition(&consistent_transition(3.4, 1.51)).unwrap();
+        assert!(noisy.confidence_score < exact.confidence_score);
+        
+        // 3σ lies outside the default 2σ band
+        transition.energy_uncertainty = Some(0.001);
+        assert!(validator.validate_transition(&transition).is_err());
+        
+        transition.energy_uncertainty = Some(-0.001);
+        let result = validator.validate_transition(&transition);
+        assert!(matches!(result, Err(QuantumValidationError::InvalidUncertainty { .. })));
+    }
+    
+    #[test]
+    fn test_deuterium_isotope_shift() {
+        let validator = QuantumValidator::new();
+        let rest_energy = |mass: f64| {
+            mass * PhysicalConstants::SPEED_OF_LIGHT.powi(2) / PhysicalConstants::ELECTRON_CHARGE
+        };
+        let balmer_alpha = |nuclear_mass: f64| {
+            let electron_mass = PhysicalConstants::ELECTRON_MASS;
+            let reduced_mass = electron_mass * nuclear_mass / (electron_mass + nuclear_mass);
+            let rydberg = rest_energy(reduced_mass) *
+                PhysicalConstants::FINE_STRUCTURE_CONSTANT.powi(2) / 2.0;
+            rydberg * (1.0 / 4.0 - 1.0 / 9.0)
+        };
+        
+        let hydrogen_energy = balmer_alpha(PhysicalConstants::PROTON_MASS);
+        let deuterium_energy = balmer_alpha(PhysicalConstants::DEUTERON_MASS);
+        
+        // Deuterium lines sit ~2.7e-4 higher in energy than protium
+        let shift = (deuterium_energy - hydrogen_energy) / hydrogen_energy;
+        assert!(shift > 2.6e-4 && shift < 2.8e-4);
+        
+        let mut transition = consistent_transition(3.4, 3.4 - deuterium_energy);
+        transition.energy_uncertainty = Some(1e-6);
+        
+        // Checked with the deuteron mass the line is consistent
+        transition.nuclear_mass_kg = Some(PhysicalConstants::DEUTERON_MASS);
+        assert!(validator.validate_hydrogenic_transition(&transition, 1, 3, 2).is_ok());
+        
+        // Attributed to protium, the shift exceeds the measurement uncertainty
+        transition.nuclear_mass_kg = Some(PhysicalConstants::PROTON_MASS);
+        assert!(matches!(
+            validator.validate_hydrogenic_transition(&transition, 1, 3, 2),
+            Err(QuantumValidationError::EnergyConservationViolation { .. }),
+        ));
+        
+        transition.nuclear_mass_kg = Some(0.0);
+        assert!(matches!(
+            validator.reduced_mass(&transition),
+            Err(QuantumValidationError::InvalidNuclearMass { .. }),
+        ));
+    }
+    
+    #[test]
+    fn test_strict_batch_rejects_low_confidence() {
+        let validator = QuantumValidator::new();
+        
+        // Within the 2σ band, so it passes the physics checks with reduced confidence
+        let mut barely_passing = consistent_transition(3.4, 1.51);
+        barely_passing.photon_energy += 0.003;
+        barely_passing.frequency = barely_passing.photon_energy *
+            PhysicalConstants::ELECTRON_CHARGE / PhysicalConstants::PLANCK_CONSTANT;
+        barely_passing.wavelength = PhysicalConstants::SPEED_OF_LIGHT / barely_passing.frequency * 1e9;
+        barely_passing.energy_uncertainty = Some(0.002);
+        let transitions = [consistent_transition(3.4, 1.51), barely_passing];
+        
+        let lenient = validator.batch_validate_transitions_strict(&transitions, 0.5).unwrap();
+        assert!(lenient.iter().all(|result| result.is_valid));
+        
+        let strict = validator.batch_validate_transitions_strict(&transitions, 0.9).unwrap();
+        assert!(strict[0].is_valid);
+        assert!(!strict[1].is_valid);
+        assert_eq!(strict[1].failed_check, Some(FailedCheck::LowConfidence));
+        assert!(strict[1].error_message.as_ref().unwrap().contains("below the required 0.9"));
+        
+        assert!(matches!(
+            validator.batch_validate_transitions_strict(&transitions, 1.5),
+            Err(QuantumValidationError::InvalidConfidenceThreshold { .. }),
+        ));
+    }
+    
+    #[test]
+    fn test_fixed_clock_timestamps_results() {
+        let validator = QuantumValidator::new().with_clock(FixedClock(1700000000));
+        let valid = consistent_transition(2.0, 1.0);
+        let mut invalid = consistent_transition(2.0, 1.0);
+        invalid.photon_energy = 5.0;
+        
+        assert_eq!(validator.validate_transition(&valid).unwrap().timestamp, 1700000000);
+        
+        let results = validator.batch_validate_transitions(&[valid, invalid]);
+        assert!(results.iter().all(|result| result.timestamp == 1700000000));
+    }
+    
+    #[test]
+    fn test_charge_conservation() {
+        let validator = QuantumValidator::new();
+        
+        // Single ionization of a neutral atom: H → H⁺ + e⁻
+        assert!(validator.validate_charge_conservation(0, 1, 1).is_ok());
+        // Auger process on a singly charged ion emits a second electron
+        assert!(validator.validate_charge_conservation(1, 2, 1).is_ok());
+        
+        let result = validator.validate_charge_conservation(0, 2, 1);
+        assert!(matches!(
+            result,
+            Err(QuantumValidationError::ChargeNotConserved { expected: 1, actual: 2 }),
+        ));
+        assert_eq!(result.unwrap_err().failed_check(), FailedCheck::ChargeConservation);
+    }
+    
+    /// Carbon monoxide fundamental band, 0 → 1 absorption from rotational level J to `j_final`
+    fn carbon_monoxide_line(j_initial: u32, j_final: u32) -> MolecularTransition {
+        let harmonic_frequency = 6.5e13; // Hz, ≈ 2170 cm⁻¹
+        let rotational_constant = 2.39e-4; // eV, ≈ 1.93 cm⁻¹
+        let level = |v: u32, j: u32| {
+            let j = j as f64;
+            PhysicalConstants::PLANCK_CONSTANT * harmonic_frequency /
+                PhysicalConstants::ELECTRON_CHARGE * (v as f64 + 0.5) +
+                rotational_constant * j * (j + 1.0)
+        };
+        
+        MolecularTransition {
+            molecule_id: "CO".to_string(),
+            v_initial: 0,
+            j_initial,
+            v_final: 1,
+            j_final,
+            harmonic_frequency,
+            rotational_constant,
+            photon_energy: (level(1, j_final) - level(0, j_initial)).abs(),
+            energy_uncertainty: None,
+            timestamp: 1640995200,
+        }
+    }
+    
+    #[test]
+    fn test_rovibrational_branches() {
+        let validator = QuantumValidator::new();
+        
+        // P branch (ΔJ = −1) lies below the band origin, R branch (ΔJ = +1) above it
+        let p_line = carbon_monoxide_line(1, 0);
+        let r_line = carbon_monoxide_line(0, 1);
+        assert!(validator.validate_rovibrational(&p_line).is_ok());
+        assert!(validator.validate_rovibrational(&r_line).is_ok());
+        assert!(p_line.photon_energy < r_line.photon_energy);
+        
+        let forbidden = carbon_monoxide_line(0, 2);
+        assert!(matches!(
+            validator.validate_rovibrational(&forbidden),
+            Err(QuantumValidationError::RotationalSelectionRuleViolation { j_initial: 0, j_final: 2 }),
+        ));
+        
+        let mut mismatched = carbon_monoxide_line(0, 1);
+        mismatched.photon_energy *= 1.001;
+        assert!(matches!(
+            validator.validate_rovibrational(&mismatched),
+            Err(QuantumValidationError::EnergyConservationViolation { .. }),
+        ));
+        
+        // A 1σ uncertainty covering the discrepancy admits the line, as for atomic transitions
+        mismatched.energy_uncertainty = Some(mismatched.photon_energy * 0.001);
+        assert!(validator.validate_rovibrational(&mismatched).is_ok());
+        mismatched.energy_uncertainty = Some(-1.0);
+        assert!(matches!(
+            validator.validate_rovibrational(&mismatched),
+            Err(QuantumValidationError::InvalidUncertainty { field: "energy_uncertainty", .. }),
+        ));
+    }
+    
+    #[test]
+    fn test_compton_scattering_at_right_angle() {
+        let validator = QuantumValidator::new();
+        let hc = PhysicalConstants::PLANCK_CONSTANT * PhysicalConstants::SPEED_OF_LIGHT /
+            PhysicalConstants::ELECTRON_CHARGE;
+        
+        // Mo Kα X-rays scattered through 90° shift by one Compton wavelength, 2.426 pm
+        let incident = 17479.0;
+        let incident_wavelength = hc / incident;
+        let scattered = hc / (incident_wavelength + 2.42631023867e-12);
+        let right_angle = std::f64::consts::FRAC_PI_2;
+        assert!(validator.validate_compton(incident, scattered, right_angle).is_ok());
+        
+        // Forward scattering leaves the photon unchanged
+        assert!(validator.validate_compton(incident, incident, 0.0).is_ok());
+        
+        let result = validator.validate_compton(incident, incident, right_angle);
+        match result {
+            Err(QuantumValidationError::ComptonKinematicsViolation { expected_shift, actual_shift }) => {
+                assert!((expected_shift - 2.426e-12).abs() < 1e-15);
+                assert_eq!(actual_shift, 0.0);
+            }
+            other => panic!("expected Compton violation, got {:?}", other),
+        }
+        
+        assert!(validator.validate_compton(-1.0, scattered, right_angle).is_err());
+    }
+    
+    #[test]
+    fn test_known_transition_table() {
+        let mut validator = QuantumValidator::new();
+        
+        // Paschen alpha and beta, absent from the old three-line table
+        assert!(validator.is_common_transition(&consistent_transition(1.51, 0.85)));
+        assert!(validator.is_common_transition(&consistent_transition(1.51, 0.544)));
+        assert!(validator.is_common_transition(&consistent_transition(3.4, 1.51)));
+        
+        // He⁺ n = 2 → 3, added as a user-supplied reference
+        let helium_line = consistent_transition(13.6, 6.05);
+        assert!(!validator.is_common_transition(&helium_line));
+        validator.add_known_transition(KnownTransition {
+            initial_energy: 13.6057,
+            final_energy: 6.0470,
+            tolerance: 0.05,
+            series: "He II".to_string(),
+        });
+        assert!(validator.is_common_transition(&helium_line));
+    }
+    
+    #[test]
+    fn test_trace_records_checks_in_order() {
+        let validator = QuantumValidator::new();
+        
+        let (result, trace) = validator.validate_transition_traced(&consistent_transition(2.0, 1.0));
+        assert!(result.unwrap().is_valid);
+        let checks: Vec<FailedCheck> = trace.iter().map(|event| event.check.clone()).collect();
+        assert_eq!(checks, [
+            FailedCheck::NonFiniteInput,
+            FailedCheck::EnergyConservation,
+            FailedCheck::InvalidEnergyLevel,
+            FailedCheck::SelectionRules,
+            FailedCheck::TransitionDirection,
+            FailedCheck::FrequencyMismatch,
+            FailedCheck::WavelengthMismatch,
+        ]);
+        assert!(trace.iter().all(|event| event.passed));
+        assert_eq!(trace[1].inputs[2], ("photon_energy".to_string(), 1.0));
+        
+        // The trace stops at the failing check and records its computed value
+        let mut invalid = consistent_transition(2.0, 1.0);
+        invalid.photon_energy = 1.5;
+        let (result, trace) = validator.validate_transition_traced(&invalid);
+        assert!(result.is_err());
+        assert_eq!(trace.len(), 2);
+        assert_eq!(trace[1].check, FailedCheck::EnergyConservation);
+        assert!(!trace[1].passed);
+        assert_eq!(trace[1].value, 0.5);
+    }
+    
+    #[test]
+    fn test_trace_records_optional_field_failures() {
+        let validator = QuantumValidator::new();
+        
+        // A non-finite optional field is counted by the finiteness check
+        let mut transition = consistent_transition(2.0, 1.0);
+        transition.source_velocity_ms = Some(f64::NAN);
+        let (result, trace) = validator.validate_transition_traced(&transition);
         assert!(result.is_err());
+        assert_eq!(trace.len(), 1);
+        assert_eq!(trace[0].check, FailedCheck::NonFiniteInput);
+        assert_eq!(trace[0].value, 1.0);
+        assert!(!trace[0].passed);
+        
+        // A non-positive nuclear mass fails its own check with the mass recorded
+        let mut transition = consistent_transition(2.0, 1.0);
+        transition.nuclear_mass_kg = Some(0.0);
+        let (result, trace) = validator.validate_transition_traced(&transition);
+        assert_eq!(result.unwrap_err().failed_check(), FailedCheck::NuclearMass);
+        assert_eq!(trace.len(), 2);
+        assert!(trace[0].passed);
+        assert_eq!(trace[1].check, FailedCheck::NuclearMass);
+        assert_eq!(trace[1].inputs, [("nuclear_mass_kg".to_string(), 0.0)]);
+        assert!(!trace[1].passed);
+        
+        // A negative uncertainty is reported with its value
+        let mut transition = consistent_transition(2.0, 1.0);
+        transition.energy_uncertainty = Some(-0.1);
+        let (result, trace) = validator.validate_transition_traced(&transition);
+        assert!(matches!(result, Err(QuantumValidationError::InvalidUncertainty { .. })));
+        assert_eq!(trace[1].check, FailedCheck::PhysicalConstraints);
+        assert_eq!(trace[1].value, -0.1);
+        assert!(!trace[1].passed);
+    }
+    
+    #[test]
+    fn test_circuit_breaker() {
+        let validator = QuantumValidator::new();
+        let valid = consistent_transition(2.0, 1.0);
+        let mut invalid = consistent_transition(2.0, 1.0);
+        invalid.photon_energy = 5.0;
+        
+        // Isolated failures never reach three in a row
+        let intermittent = [
+            invalid.clone(),
+            invalid.clone(),
+            valid.clone(),
+            invalid.clone(),
+            valid.clone(),
+        ];
+        let (results, tripped) = validator.batch_validate_transitions_with_breaker(&intermittent, 3);
+        assert!(!tripped);
+        assert_eq!(results.len(), 5);
+        
+        // A corrupt tail trips the breaker before the remaining records are processed
+        let mut corrupt = vec![valid.clone()];
+        corrupt.extend(std::iter::repeat_n(invalid, 100));
+        let (results, tripped) = validator.batch_validate_transitions_with_breaker(&corrupt, 3);
+        assert!(tripped);
+        assert_eq!(results.len(), 4);
+        assert!(results[0].is_valid);
+    }
+    
+    #[test]
+    fn test_forbidden_transition_strict_vs_penalized() {
+        // Hydrogen 2s ↔ 1s has Δl = 0, forbidden as an electric dipole transition
+        let mut transition = consistent_transition(13.6, 3.4);
+        transition.orbital_angular_momentum = Some((0, 0));
+        
+        let strict = QuantumValidator::new();
+        assert!(matches!(
+            strict.validate_transition(&transition),
+            Err(QuantumValidationError::SelectionRulesViolation)
+        ));
+        
+        let lenient = QuantumValidator::new().with_forbidden_transitions(true);
+        let result = lenient.validate_transition(&transition).unwrap();
+        assert!(result.is_valid);
+        assert!(result.error_message.is_none());
+        assert_eq!(result.notes, [FORBIDDEN_TRANSITION_NOTE]);
+        assert!(result.confidence_score <= FORBIDDEN_TRANSITION_PENALTY);
+        assert_eq!(crate::utils::summarize_results(std::slice::from_ref(&result)).valid_count, 1);
+        
+        // The traced path agrees and marks the selection rules as penalized
+        let (traced, trace) = lenient.validate_transition_traced(&transition);
+        assert_eq!(traced.unwrap().confidence_score, result.confidence_score);
+        let selection = trace
+            .iter()
+            .find(|event| event.check == FailedCheck::SelectionRules)
+            .unwrap();
+        assert!(selection.passed && selection.penalized);
+        assert!(lenient.explain(&transition).all_passed());
+        
+        // Allowed transitions are unaffected by the setting
+        transition.orbital_angular_momentum = Some((1, 0));
+        let allowed = lenient.validate_transition(&transition).unwrap();
+        assert_eq!(
+            allowed.confidence_score,
+            strict.validate_transition(&transition).unwrap().confidence_score
+        );
+        assert!(allowed.notes.is_empty());
+        
+        // A cached penalized acceptance does not survive switching back to strict
+        let cached = QuantumValidator::new().with_cache(8).with_forbidden_transitions(true);
+        transition.orbital_angular_momentum = Some((0, 0));
+        assert!(cached.validate_transition(&transition).is_ok());
+        let cached = cached.with_forbidden_transitions(false);
+        assert!(cached.validate_transition(&transition).is_err());
+    }
+    
+    #[test]
+    fn test_invalid_levels_rejected_even_when_forbidden_allowed() {
+        let lenient = QuantumValidator::new().with_forbidden_transitions(true);
+        
+        assert!(matches!(
+            lenient.validate_transition(&consistent_transition(-1.0, 2.0)),
+            Err(QuantumValidationError::InvalidEnergyLevel { level }) if level == -1.0
+        ));
+        
+        let mut unchanged = consistent_transition(2.0, 1.0);
+        unchanged.final_energy = 2.0;
+        unchanged.photon_energy = 0.0;
+        match lenient.validate_transition(&unchanged) {
+            Err(error @ QuantumValidationError::NoLevelChange { .. }) => {
+                assert_eq!(error.failed_check(), FailedCheck::InvalidEnergyLevel);
+            }
+            other => panic!("expected no level change, got {:?}", other),
+        }
     }
 }
\ No newline at end of file
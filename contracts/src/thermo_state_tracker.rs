@@ -2,82 +2,479 @@
 //! 
 //! Tracks and validates thermodynamic state changes and phase transitions
 
-use crate::types::{ThermodynamicState, PhaseState, ValidationResult, PhysicalConstants};
+use crate::types::{
+    ThermodynamicState, PhaseState, ProcessConstraint, HeatExchange, Spontaneity, ToleranceMode,
+    ValidationResult, FailedCheck, FailureObserver, Clock, SystemClock, ConfidencePrecision,
+    ConfidenceWeights, ConstantsSet, SubstanceProperties, Tolerances, Validator, ValidationFailure,
+    MoleculeType,
+};
+use crate::utils;
 use serde::{Deserialize, Serialize};
 use fabric_contract_api::contract::Contract;
 use fabric_contract_api::info::Info;
+use std::collections::HashMap;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum ThermoValidationError {
     #[error("Second law violation: entropy decreased from {initial} to {final}")]
     EntropyDecrease { initial: f64, final: f64 },
+    #[error("Second law violation: total entropy production {production} J/K is negative")]
+    NegativeEntropyProduction { production: f64 },
     #[error("Invalid phase transition from {from:?} to {to:?}")]
     InvalidPhaseTransition { from: PhaseState, to: PhaseState },
     #[error("Gibbs free energy violation: ΔG = {delta_g} > 0")]
     GibbsFreeEnergyViolation { delta_g: f64 },
+    #[error("Stored Gibbs free energy {stored} J disagrees with H − TS = {computed} J")]
+    GibbsFieldInconsistent { stored: f64, computed: f64 },
+    #[error("Gibbs free energy violation at negative temperature: ΔG = {delta_g} < 0")]
+    InvertedGibbsFreeEnergyViolation { delta_g: f64 },
     #[error("Temperature below absolute zero: {temperature} K")]
     TemperatureBelowAbsoluteZero { temperature: f64 },
     #[error("Invalid pressure: {pressure} Pa")]
     InvalidPressure { pressure: f64 },
+    #[error("Plasma temperature {temperature} K is below the ionization threshold")]
+    PlasmaTemperatureTooLow { temperature: f64 },
+    #[error("Supercritical state at {temperature} K and {pressure} Pa is below the critical point ({critical_temperature} K, {critical_pressure} Pa)")]
+    BelowCriticalPoint {
+        temperature: f64,
+        pressure: f64,
+        critical_temperature: f64,
+        critical_pressure: f64,
+    },
+    #[error("Invalid volume: {volume} m³")]
+    InvalidVolume { volume: f64 },
+    #[error("Non-finite input in field {field}")]
+    NonFiniteInput { field: &'static str },
+    #[error("Process constraint violated: {constraint:?}")]
+    ProcessConstraintViolated { constraint: ProcessConstraint },
+    #[error("Carnot efficiency exceeded: claimed {claimed}, limit {limit}")]
+    CarnotEfficiencyExceeded { claimed: f64, limit: f64 },
+    #[error("Invalid reservoir temperatures: cold {t_cold} K, hot {t_hot} K")]
+    InvalidReservoirTemperatures { t_cold: f64, t_hot: f64 },
+    #[error("Invalid heat input: {heat_in} J")]
+    InvalidHeatInput { heat_in: f64 },
+    #[error("Ideal gas law violated: expected pressure {expected_pressure} Pa, got {actual_pressure} Pa")]
+    IdealGasInconsistent { expected_pressure: f64, actual_pressure: f64 },
+    #[error("Invalid amount of substance: {n_moles} mol")]
+    InvalidMoleCount { n_moles: f64 },
+    #[error("Invalid temperature: {temperature} K")]
+    InvalidTemperature { temperature: f64 },
+    #[error("Invalid heat capacity ratio: {gamma}")]
+    InvalidHeatCapacityRatio { gamma: f64 },
+    #[error("Invalid molar mass: {molar_mass} kg/mol")]
+    InvalidMolarMass { molar_mass: f64 },
+    #[error("Process path needs at least two states, got {length}")]
+    PathTooShort { length: usize },
+    #[error("Phase {declared:?} is not stable at these conditions; expected {stable:?}")]
+    PhaseNotStableAtConditions { declared: PhaseState, stable: PhaseState },
+    #[error("Invalid number of interpolation steps: {steps}")]
+    InvalidStepCount { steps: usize },
+    #[error("Process path step {step} failed: {source}")]
+    PathStepViolation { step: usize, source: Box<ThermoValidationError> },
+    #[error("Temperature {temperature} K exceeds BEC critical temperature {critical} K")]
+    BecTemperatureTooHigh { temperature: f64, critical: f64 },
+    #[error("Invalid BEC parameters: particle mass {particle_mass} kg, number density {number_density} m⁻³")]
+    InvalidBecParameters { particle_mass: f64, number_density: f64 },
+    #[error("Ionization fraction {fraction} at {temperature} K is too low for a plasma")]
+    IonizationTooLow { fraction: f64, temperature: f64 },
+    #[error("Invalid ionization parameters: ionization energy {ionization_energy} eV, electron density {electron_density} m⁻³")]
+    InvalidIonizationParameters { ionization_energy: f64, electron_density: f64 },
+    #[error("Timestamp at index {index} is not after its predecessor: {prev} then {curr}")]
+    NonMonotonicTimestamp { index: usize, prev: u64, curr: u64 },
+    #[error("Enthalpy not conserved in throttling: ΔH = {delta_h} J")]
+    EnthalpyNotConserved { delta_h: f64 },
+    #[error("No pressure change at {pressure} Pa")]
+    NoPressureChange { pressure: f64 },
+    #[error("Transition temperature mismatch: expected {expected} K, got {actual} K")]
+    TransitionTemperatureMismatch { expected: f64, actual: f64 },
+    #[error("No phase coexistence at {pressure} Pa")]
+    NoPhaseCoexistence { pressure: f64 },
+    #[error("Latent heat mismatch: expected ΔH = {expected} J, got {actual} J")]
+    LatentHeatMismatch { expected: f64, actual: f64 },
+    #[error("Energy transfer imbalance: residual {residual} J")]
+    EnergyTransferImbalance { residual: f64 },
+    #[error("Second law violation: negative transfer losses {losses} J")]
+    NegativeTransferLosses { losses: f64 },
+}
+
+impl ThermoValidationError {
+    /// Returns the validation rule this error corresponds to
+    pub fn failed_check(&self) -> FailedCheck {
+        match self {
+            ThermoValidationError::EntropyDecrease { .. } |
+            ThermoValidationError::NegativeEntropyProduction { .. } => FailedCheck::EntropyDecrease,
+            ThermoValidationError::InvalidPhaseTransition { .. } |
+            ThermoValidationError::TransitionTemperatureMismatch { .. } |
+            ThermoValidationError::NoPhaseCoexistence { .. } => FailedCheck::PhaseTransition,
+            ThermoValidationError::GibbsFreeEnergyViolation { .. } |
+            ThermoValidationError::InvertedGibbsFreeEnergyViolation { .. } |
+            ThermoValidationError::GibbsFieldInconsistent { .. } => FailedCheck::GibbsFreeEnergy,
+            ThermoValidationError::TemperatureBelowAbsoluteZero { .. } |
+            ThermoValidationError::InvalidPressure { .. } |
+            ThermoValidationError::InvalidVolume { .. } |
+            ThermoValidationError::PlasmaTemperatureTooLow { .. } |
+            ThermoValidationError::BelowCriticalPoint { .. } |
+            ThermoValidationError::InvalidTemperature { .. } |
+            ThermoValidationError::InvalidHeatCapacityRatio { .. } |
+            ThermoValidationError::InvalidMolarMass { .. } => FailedCheck::PhysicalConstraints,
+            ThermoValidationError::NonFiniteInput { .. } => FailedCheck::NonFiniteInput,
+            ThermoValidationError::ProcessConstraintViolated { .. } => FailedCheck::ProcessConstraint,
+            ThermoValidationError::CarnotEfficiencyExceeded { .. } |
+            ThermoValidationError::InvalidReservoirTemperatures { .. } |
+            ThermoValidationError::InvalidHeatInput { .. } => FailedCheck::CarnotBound,
+            ThermoValidationError::IdealGasInconsistent { .. } |
+            ThermoValidationError::InvalidMoleCount { .. } => FailedCheck::IdealGasConsistency,
+            ThermoValidationError::PathTooShort { .. } |
+            ThermoValidationError::InvalidStepCount { .. } => FailedCheck::ProcessPath,
+            ThermoValidationError::PathStepViolation { source, .. } => source.failed_check(),
+            ThermoValidationError::BecTemperatureTooHigh { .. } |
+            ThermoValidationError::InvalidBecParameters { .. } => FailedCheck::BecFeasibility,
+            ThermoValidationError::PhaseNotStableAtConditions { .. } => FailedCheck::PhaseStability,
+            ThermoValidationError::IonizationTooLow { .. } |
+            ThermoValidationError::InvalidIonizationParameters { .. } => FailedCheck::Ionization,
+            ThermoValidationError::NonMonotonicTimestamp { .. } => FailedCheck::TemporalOrdering,
+            ThermoValidationError::EnthalpyNotConserved { .. } |
+            ThermoValidationError::NoPressureChange { .. } => FailedCheck::ProcessConstraint,
+            ThermoValidationError::LatentHeatMismatch { .. } => FailedCheck::LatentHeat,
+            ThermoValidationError::EnergyTransferImbalance { .. } |
+            ThermoValidationError::NegativeTransferLosses { .. } => FailedCheck::EnergyTransfer,
+        }
+    }
 }
 
 #[derive(Info)]
 pub struct ThermoStateTracker {
-    entropy_tolerance: f64,
-    gibbs_tolerance: f64,
+    tolerances: Tolerances,
+    entropy_tolerance_mode: ToleranceMode,
+    allow_bec_formation: bool,
+    allow_negative_temperature: bool,
+    plasma_temperature_threshold: f64,
+    amount_of_substance: f64,
+    confidence_precision: ConfidencePrecision,
+    confidence_weights: ConfidenceWeights,
+    constants: ConstantsSet,
+    on_failure: Option<FailureObserver>,
+    clock: Box<dyn Clock>,
+    substances: HashMap<String, SubstanceProperties>,
 }
 
 impl Contract for ThermoStateTracker {
     fn new() -> Self {
         ThermoStateTracker {
-            entropy_tolerance: 1e-6, // J/K
-            gibbs_tolerance: 1e-3,   // J
+            tolerances: Tolerances::default(),
+            entropy_tolerance_mode: ToleranceMode::Absolute,
+            allow_bec_formation: false,
+            allow_negative_temperature: false,
+            plasma_temperature_threshold: 5000.0,
+            amount_of_substance: 1.0, // mol, for melting and freezing
+            confidence_precision: ConfidencePrecision::default(),
+            confidence_weights: ConfidenceWeights::default(),
+            constants: ConstantsSet::codata_2018(),
+            on_failure: None,
+            clock: Box::new(SystemClock),
+            substances: utils::builtin_substances(),
         }
     }
 }
 
+impl ValidationFailure for ThermoValidationError {
+    fn failed_check(&self) -> FailedCheck {
+        ThermoValidationError::failed_check(self)
+    }
+}
+
+impl Validator for ThermoStateTracker {
+    type Input = (ThermodynamicState, ThermodynamicState);
+    type Error = ThermoValidationError;
+    
+    fn validate(
+        &self,
+        input: &(ThermodynamicState, ThermodynamicState),
+    ) -> Result<ValidationResult, ThermoValidationError> {
+        self.validate_state_change(&input.0, &input.1)
+    }
+}
+
 impl ThermoStateTracker {
+    /// Replaces every tolerance at once, e.g. to match an instrument's precision
+    pub fn with_tolerances(mut self, tolerances: Tolerances) -> Self {
+        self.tolerances = tolerances;
+        self
+    }
+    
+    /// Sets whether the entropy tolerance is absolute (J/K) or relative to the initial entropy
+    pub fn with_entropy_tolerance_mode(mut self, mode: ToleranceMode) -> Self {
+        self.entropy_tolerance_mode = mode;
+        self
+    }
+    
+    /// Sets whether gases and liquids may condense directly into a Bose-Einstein condensate
+    pub fn with_bec_formation(mut self, allow: bool) -> Self {
+        self.allow_bec_formation = allow;
+        self
+    }
+    
+    /// Sets whether population-inverted states may have negative absolute temperatures
+    pub fn with_negative_temperature(mut self, allow: bool) -> Self {
+        self.allow_negative_temperature = allow;
+        self
+    }
+    
+    /// Sets the temperature in Kelvin below which a Plasma state is rejected as unphysical
+    pub fn with_plasma_temperature_threshold(mut self, threshold: f64) -> Self {
+        self.plasma_temperature_threshold = threshold;
+        self
+    }
+    
+    /// Sets the amount of substance in moles whose latent heat melting and freezing must match
+    ///
+    /// Boiling and condensation take it from the gas-side state via n = PV/RT instead.
+    pub fn with_amount_of_substance(mut self, n_moles: f64) -> Self {
+        self.amount_of_substance = n_moles;
+        self
+    }
+    
+    /// Uses the given set of physical constants, e.g. an older CODATA revision
+    pub fn with_constants(mut self, constants: ConstantsSet) -> Self {
+        self.constants = constants;
+        self
+    }
+    
+    /// Uses `clock` to timestamp results, e.g. a `FixedClock` for deterministic output
+    pub fn with_clock<C: Clock + 'static>(mut self, clock: C) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+    
+    /// Sets the number of decimal places reported confidence scores are rounded to
+    pub fn with_confidence_precision(mut self, precision: ConfidencePrecision) -> Self {
+        self.confidence_precision = precision;
+        self
+    }
+    
+    /// Replaces the confidence penalties and boosts, e.g. after calibrating against historical data
+    pub fn with_confidence_weights(mut self, weights: ConfidenceWeights) -> Self {
+        self.confidence_weights = weights;
+        self
+    }
+    
+    /// Registers a callback invoked for every failed result in batch validation
+    pub fn with_failure_observer<F>(mut self, observer: F) -> Self
+    where
+        F: Fn(&ValidationResult) + Send + Sync + 'static,
+    {
+        self.on_failure = Some(Box::new(observer));
+        self
+    }
+    
     /// Validates a thermodynamic state change
     pub fn validate_state_change(
         &self,
         initial_state: &ThermodynamicState,
         final_state: &ThermodynamicState,
     ) -> Result<ValidationResult, ThermoValidationError> {
+        self.validate_state_change_with_constraint(initial_state, final_state, None)
+    }
+    
+    /// Validates a state change and returns the final state marked as validated
+    ///
+    /// On failure the error is returned and no validated copy is produced.
+    pub fn validate_state_change_owned(
+        &self,
+        initial_state: &ThermodynamicState,
+        mut final_state: ThermodynamicState,
+    ) -> Result<ThermodynamicState, ThermoValidationError> {
+        self.validate_state_change(initial_state, &final_state)?;
+        
+        final_state.validated = true;
+        Ok(final_state)
+    }
+    
+    /// Validates a thermodynamic state change, optionally enforcing a process constraint
+    pub fn validate_state_change_with_constraint(
+        &self,
+        initial_state: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+        constraint: Option<ProcessConstraint>,
+    ) -> Result<ValidationResult, ThermoValidationError> {
+        self.validate_state_change_with_context(initial_state, final_state, constraint, None)
+    }
+    
+    /// Validates a thermodynamic state change, scoring confidence by how reversible it was
+    ///
+    /// The heat exchanged with the reservoir determines the entropy production;
+    /// near-reversible processes keep full confidence and strongly irreversible
+    /// ones are penalized even though they satisfy the second law. The system's
+    /// entropy may fall as long as the total entropy change ΔS − Q/T_res does not.
+    pub fn validate_state_change_with_heat_exchange(
+        &self,
+        initial_state: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+        heat_exchange: &HeatExchange,
+    ) -> Result<ValidationResult, ThermoValidationError> {
+        self.validate_state_change_with_context(initial_state, final_state, None, Some(heat_exchange))
+    }
+    
+    /// Validates an adiabatic state change against the heat actually exchanged
+    ///
+    /// Unlike `ProcessConstraint::Adiabatic` alone, which can only assume a
+    /// reversible process, this accepts irreversible adiabatic steps such as a
+    /// free expansion as long as no heat crossed the boundary.
+    pub fn validate_adiabatic_state_change(
+        &self,
+        initial_state: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+        heat_exchange: &HeatExchange,
+    ) -> Result<ValidationResult, ThermoValidationError> {
+        self.validate_state_change_with_context(
+            initial_state,
+            final_state,
+            Some(ProcessConstraint::Adiabatic),
+            Some(heat_exchange),
+        )
+    }
+    
+    /// Runs the full validation pipeline with every optional piece of process context
+    fn validate_state_change_with_context(
+        &self,
+        initial_state: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+        constraint: Option<ProcessConstraint>,
+        heat_exchange: Option<&HeatExchange>,
+    ) -> Result<ValidationResult, ThermoValidationError> {
+        // Reject NaN and infinite fields before any comparisons
+        self.validate_finite_inputs(initial_state)?;
+        self.validate_finite_inputs(final_state)?;
+        
         // Validate basic physical constraints
         self.validate_physical_constraints(final_state)?;
         
+        // Enforce the invariant of a constrained process
+        if let Some(constraint) = constraint {
+            self.validate_process_constraint(initial_state, final_state, constraint, heat_exchange)?;
+        }
+        
         // Check second law of thermodynamics
-        self.validate_entropy_increase(initial_state, final_state)?;
+        self.validate_entropy_increase(initial_state, final_state, heat_exchange)?;
         
         // Validate phase transition
         self.validate_phase_transition(&initial_state.phase, &final_state.phase)?;
         
+        // Check the enthalpy jump of a phase change against the latent heat
+        self.validate_phase_change_latent_heat(initial_state, final_state)?;
+        
         // Check Gibbs free energy for spontaneous processes
         self.validate_gibbs_free_energy(initial_state, final_state)?;
         
+        // Measure irreversibility when the heat exchanged is known
+        let irreversibility = match heat_exchange {
+            Some(heat_exchange) => Some(self.irreversibility(initial_state, final_state, heat_exchange)?),
+            None => None,
+        };
+        
         // Calculate confidence score
-        let confidence = self.calculate_confidence_score(initial_state, final_state);
+        let confidence = self.confidence_precision.round(
+            self.calculate_confidence_score(initial_state, final_state, irreversibility),
+        );
         
         Ok(ValidationResult {
             is_valid: true,
             error_message: None,
             confidence_score: confidence,
             validator_consensus: vec!["thermo_state_tracker".to_string()],
-            timestamp: std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
+            timestamp: self.clock.now_secs(),
+            failed_check: None,
+            notes: Vec::new(),
+        })
+    }
+    
+    /// Validates a multi-step process by checking every consecutive pair of states
+    ///
+    /// Fails on the first violating step; the overall confidence is the product
+    /// of the per-step confidence scores.
+    pub fn validate_path(
+        &self,
+        states: &[ThermodynamicState],
+    ) -> Result<ValidationResult, ThermoValidationError> {
+        if states.len() < 2 {
+            return Err(ThermoValidationError::PathTooShort { length: states.len() });
+        }
+        
+        let mut confidence = 1.0;
+        
+        for (step, pair) in states.windows(2).enumerate() {
+            let result = self.validate_state_change(&pair[0], &pair[1]).map_err(|error| {
+                ThermoValidationError::PathStepViolation {
+                    step,
+                    source: Box::new(error),
+                }
+            })?;
+            confidence *= result.confidence_score;
+        }
+        
+        Ok(ValidationResult {
+            is_valid: true,
+            error_message: None,
+            confidence_score: self.confidence_precision.round(confidence),
+            validator_consensus: vec!["thermo_state_tracker".to_string()],
+            timestamp: self.clock.now_secs(),
+            failed_check: None,
+            notes: Vec::new(),
         })
     }
     
+    /// Validates that timestamps strictly increase along a sequence of states
+    pub fn validate_temporal_ordering(
+        &self,
+        states: &[ThermodynamicState],
+    ) -> Result<(), ThermoValidationError> {
+        for (index, pair) in states.windows(2).enumerate() {
+            if pair[1].timestamp <= pair[0].timestamp {
+                return Err(ThermoValidationError::NonMonotonicTimestamp {
+                    index: index + 1,
+                    prev: pair[0].timestamp,
+                    curr: pair[1].timestamp,
+                });
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Validates that all numeric fields are finite
+    fn validate_finite_inputs(
+        &self,
+        state: &ThermodynamicState,
+    ) -> Result<(), ThermoValidationError> {
+        let fields = [
+            ("temperature", state.temperature),
+            ("pressure", state.pressure),
+            ("volume", state.volume),
+            ("entropy", state.entropy),
+            ("enthalpy", state.enthalpy),
+            ("gibbs_energy", state.gibbs_energy),
+            ("internal_energy", state.internal_energy),
+        ];
+        
+        for (field, value) in fields {
+            if !value.is_finite() {
+                return Err(ThermoValidationError::NonFiniteInput { field });
+            }
+        }
+        
+        Ok(())
+    }
+    
     /// Validates basic physical constraints
     fn validate_physical_constraints(
         &self,
         state: &ThermodynamicState,
     ) -> Result<(), ThermoValidationError> {
-        // Temperature must be above absolute zero
-        if state.temperature < 0.0 {
+        // Temperature must be above absolute zero, unless the opt-in for
+        // population-inverted systems applies
+        let inverted = self.allow_negative_temperature && state.population_inverted;
+        if state.temperature < 0.0 && !inverted {
             return Err(ThermoValidationError::TemperatureBelowAbsoluteZero {
                 temperature: state.temperature,
             });
@@ -90,22 +487,121 @@ impl ThermoStateTracker {
             });
         }
         
+        // A plasma needs enough thermal energy to stay ionized
+        if state.phase == PhaseState::Plasma && state.temperature < self.plasma_temperature_threshold {
+            return Err(ThermoValidationError::PlasmaTemperatureTooLow {
+                temperature: state.temperature,
+            });
+        }
+        
+        // A supercritical fluid lies beyond the critical point of a known substance
+        if state.phase == PhaseState::SuperCritical {
+            if let Some(substance) = self.substances.get(&state.substance_id) {
+                if state.temperature < substance.critical_temperature ||
+                    state.pressure < substance.critical_pressure
+                {
+                    return Err(ThermoValidationError::BelowCriticalPoint {
+                        temperature: state.temperature,
+                        pressure: state.pressure,
+                        critical_temperature: substance.critical_temperature,
+                        critical_pressure: substance.critical_pressure,
+                    });
+                }
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Validates the invariant implied by a process constraint
+    ///
+    /// An adiabatic step is checked against the heat exchanged when it is known,
+    /// Q ≈ 0 relative to the internal energy; otherwise it is taken to be
+    /// reversible and must leave the entropy unchanged.
+    fn validate_process_constraint(
+        &self,
+        initial: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+        constraint: ProcessConstraint,
+        heat_exchange: Option<&HeatExchange>,
+    ) -> Result<(), ThermoValidationError> {
+        let holds = match constraint {
+            ProcessConstraint::Isothermal => {
+                self.is_unchanged(initial.temperature, final_state.temperature)
+            }
+            ProcessConstraint::Isentropic => {
+                (final_state.entropy - initial.entropy).abs() <= self.entropy_tolerance(initial)
+            }
+            ProcessConstraint::Isobaric => {
+                self.is_unchanged(initial.pressure, final_state.pressure)
+            }
+            ProcessConstraint::Isochoric => {
+                self.is_unchanged(initial.volume, final_state.volume)
+            }
+            ProcessConstraint::Adiabatic => match heat_exchange {
+                Some(heat_exchange) => {
+                    let delta_u = final_state.internal_energy - initial.internal_energy;
+                    let scale = initial.internal_energy.abs().max(delta_u.abs());
+                    heat_exchange.heat.abs() <= self.tolerances.process * scale
+                }
+                None => {
+                    (final_state.entropy - initial.entropy).abs() <= self.entropy_tolerance(initial)
+                }
+            },
+        };
+        
+        if !holds {
+            return Err(ThermoValidationError::ProcessConstraintViolated { constraint });
+        }
+        
         Ok(())
     }
     
+    /// Checks whether a state variable stayed constant within the relative process tolerance
+    fn is_unchanged(&self, initial: f64, final_value: f64) -> bool {
+        (final_value - initial).abs() <= self.tolerances.process * initial.abs()
+    }
+    
+    /// Entropy tolerance in J/K for a change starting at `initial`, per the tolerance mode
+    fn entropy_tolerance(&self, initial: &ThermodynamicState) -> f64 {
+        match self.entropy_tolerance_mode {
+            ToleranceMode::Absolute => self.tolerances.entropy,
+            ToleranceMode::Relative => self.tolerances.entropy * initial.entropy.abs(),
+        }
+    }
+    
     /// Validates entropy increase (Second Law of Thermodynamics)
+    ///
+    /// Without heat exchange the system is treated as isolated and its own entropy
+    /// must not decrease; with it, the total change of system and reservoir is checked.
     fn validate_entropy_increase(
         &self,
         initial: &ThermodynamicState,
-        final: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+        heat_exchange: Option<&HeatExchange>,
     ) -> Result<(), ThermoValidationError> {
-        let entropy_change = final.entropy - initial.entropy;
+        let entropy_change = final_state.entropy - initial.entropy;
+        let tolerance = self.entropy_tolerance(initial);
+        
+        // Open systems may export entropy to the reservoir, but the total must not decrease
+        if let Some(heat_exchange) = heat_exchange {
+            let production = self.entropy_production(
+                initial,
+                final_state,
+                heat_exchange.heat,
+                heat_exchange.reservoir_temperature,
+            )?;
+            if production < -tolerance {
+                return Err(ThermoValidationError::NegativeEntropyProduction { production });
+            }
+            return Ok(());
+        }
         
         // For isolated systems, entropy must increase or stay constant
-        if entropy_change < -self.entropy_tolerance {
+        if entropy_change < -tolerance {
             return Err(ThermoValidationError::EntropyDecrease {
                 initial: initial.entropy,
-                final: final.entropy,
+                final: final_state.entropy,
             });
         }
         
@@ -120,7 +616,7 @@ impl ThermoStateTracker {
     ) -> Result<(), ThermoValidationError> {
         use PhaseState::*;
         
-        let valid_transitions = match from {
+        let mut valid_transitions = match from {
             Solid => vec![Liquid, Gas, Plasma], // Melting, sublimation, or extreme heating
             Liquid => vec![Solid, Gas, SuperCritical], // Freezing, vaporization, or critical point
             Gas => vec![Liquid, Solid, Plasma, SuperCritical], // Condensation, deposition, ionization, or critical point
@@ -129,6 +625,10 @@ impl ThermoStateTracker {
             BoseEinsteinCondensate => vec![Solid, Liquid, Gas], // Heating
         };
         
+        if self.allow_bec_formation && matches!(from, Gas | Liquid) {
+            valid_transitions.push(BoseEinsteinCondensate); // Condensation below T_c
+        }
+        
         if from != to && !valid_transitions.contains(to) {
             return Err(ThermoValidationError::InvalidPhaseTransition {
                 from: from.clone(),
@@ -143,50 +643,119 @@ impl ThermoStateTracker {
     fn validate_gibbs_free_energy(
         &self,
         initial: &ThermodynamicState,
-        final: &ThermodynamicState,
+        final_state: &ThermodynamicState,
     ) -> Result<(), ThermoValidationError> {
-        // Calculate Gibbs free energy change: ΔG = ΔH - TΔS
-        let delta_h = final.enthalpy - initial.enthalpy;
-        let delta_s = final.entropy - initial.entropy;
-        let avg_temp = (initial.temperature + final.temperature) / 2.0;
-        let delta_g = delta_h - avg_temp * delta_s;
+        let delta_g = self.gibbs_energy_change(initial, final_state);
+        
+        // At negative temperature TdS ≤ δQ, so G is maximized instead: ΔG ≥ 0.
+        // The second law itself, ΔS ≥ 0, holds at either sign of T.
+        if initial.temperature < 0.0 && final_state.temperature < 0.0 {
+            if delta_g < -self.tolerances.gibbs {
+                return Err(ThermoValidationError::InvertedGibbsFreeEnergyViolation { delta_g });
+            }
+            return Ok(());
+        }
         
         // For spontaneous processes at constant T and P, ΔG ≤ 0
-        if delta_g > self.gibbs_tolerance {
+        if delta_g > self.tolerances.gibbs {
             return Err(ThermoValidationError::GibbsFreeEnergyViolation { delta_g });
         }
         
         Ok(())
     }
     
+    /// Calculates the Gibbs free energy change: ΔG = ΔH - TΔS
+    fn gibbs_energy_change(
+        &self,
+        initial: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+    ) -> f64 {
+        let delta_h = final_state.enthalpy - initial.enthalpy;
+        let delta_s = final_state.entropy - initial.entropy;
+        let avg_temp = (initial.temperature + final_state.temperature) / 2.0;
+        delta_h - avg_temp * delta_s
+    }
+    
+    /// Classifies a state change by the sign of ΔG relative to the Gibbs tolerance
+    pub fn classify_spontaneity(
+        &self,
+        initial_state: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+    ) -> Spontaneity {
+        let delta_g = self.gibbs_energy_change(initial_state, final_state);
+        
+        if delta_g < -self.tolerances.gibbs {
+            Spontaneity::Spontaneous
+        } else if delta_g > self.tolerances.gibbs {
+            Spontaneity::NonSpontaneous
+        } else {
+            Spontaneity::Equilibrium
+        }
+    }
+    
     /// Calculates confidence score for the validation
     fn calculate_confidence_score(
         &self,
         initial: &ThermodynamicState,
-        final: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+        irreversibility: Option<f64>,
     ) -> f64 {
+        let weights = &self.confidence_weights;
         let mut score = 1.0;
         
+        // Reduce score for strongly irreversible processes
+        if let Some(irreversibility) = irreversibility {
+            score *= 1.0 - weights.irreversibility_penalty * irreversibility;
+        }
+        
         // Reduce score for extreme temperature changes
-        let temp_change_ratio = (final.temperature - initial.temperature).abs() / initial.temperature;
-        if temp_change_ratio > 2.0 {
-            score *= 0.8;
+        let temp_change_ratio =
+            (final_state.temperature - initial.temperature).abs() / initial.temperature;
+        if temp_change_ratio > weights.temperature_change_ratio {
+            score *= weights.temperature_penalty;
         }
         
         // Reduce score for extreme pressure changes
-        let pressure_change_ratio = (final.pressure - initial.pressure).abs() / initial.pressure;
-        if pressure_change_ratio > 10.0 {
-            score *= 0.9;
+        let pressure_change_ratio =
+            (final_state.pressure - initial.pressure).abs() / initial.pressure;
+        if pressure_change_ratio > weights.pressure_change_ratio {
+            score *= weights.pressure_penalty;
         }
         
         // Boost score for common phase transitions
-        if self.is_common_phase_transition(&initial.phase, &final.phase) {
-            score *= 1.1;
+        if self.is_common_phase_transition(&initial.phase, &final_state.phase) {
+            score *= weights.common_transition_boost;
         }
         
         score.min(1.0).max(0.0)
     }
     
+    /// Fraction of the entropy budget |ΔS| + |Q/T| that is entropy production
+    ///
+    /// 0 for a reversible process, 1 when all entropy change is generated
+    /// internally, as in a free expansion.
+    fn irreversibility(
+        &self,
+        initial: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+        heat_exchange: &HeatExchange,
+    ) -> Result<f64, ThermoValidationError> {
+        let sigma = self.entropy_production(
+            initial,
+            final_state,
+            heat_exchange.heat,
+            heat_exchange.reservoir_temperature,
+        )?;
+        let budget = (final_state.entropy - initial.entropy).abs() +
+            (heat_exchange.heat / heat_exchange.reservoir_temperature).abs();
+        
+        if budget == 0.0 {
+            return Ok(0.0);
+        }
+        
+        Ok((sigma.abs() / budget).min(1.0))
+    }
+    
     /// Checks if this is a common phase transition
     fn is_common_phase_transition(&self, from: &PhaseState, to: &PhaseState) -> bool {
         use PhaseState::*;
@@ -196,109 +765,899 @@ impl ThermoStateTracker {
         )
     }
     
-    /// Calculates equilibrium properties for a given state
-    pub fn calculate_equilibrium_properties(
+    /// Calculates the Joule–Thomson coefficient µ_JT = ΔT/ΔP in K/Pa
+    ///
+    /// Positive values mean the gas cools on expansion. Only meaningful for an
+    /// isenthalpic process; see `validate_joule_thomson_expansion`.
+    pub fn joule_thomson_coefficient(
         &self,
-        state: &ThermodynamicState,
-    ) -> ThermodynamicState {
-        let mut equilibrium_state = state.clone();
+        initial_state: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+    ) -> Result<f64, ThermoValidationError> {
+        let delta_p = final_state.pressure - initial_state.pressure;
+        if delta_p == 0.0 {
+            return Err(ThermoValidationError::NoPressureChange {
+                pressure: initial_state.pressure,
+            });
+        }
         
-        // Calculate internal energy using ideal gas approximation
-        // U = nCvT for ideal gas
-        let n_moles = 1.0; // Assume 1 mole for simplicity
-        let cv = 1.5 * PhysicalConstants::GAS_CONSTANT; // Monatomic ideal gas
-        equilibrium_state.internal_energy = n_moles * cv * state.temperature;
+        Ok((final_state.temperature - initial_state.temperature) / delta_p)
+    }
+    
+    /// Validates a throttling process conserved enthalpy and returns its Joule–Thomson coefficient
+    pub fn validate_joule_thomson_expansion(
+        &self,
+        initial_state: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+    ) -> Result<f64, ThermoValidationError> {
+        if !self.is_unchanged(initial_state.enthalpy, final_state.enthalpy) {
+            return Err(ThermoValidationError::EnthalpyNotConserved {
+                delta_h: final_state.enthalpy - initial_state.enthalpy,
+            });
+        }
         
-        // Calculate enthalpy: H = U + P V
-        equilibrium_state.enthalpy = equilibrium_state.internal_energy + 
-            state.pressure * state.volume;
+        self.joule_thomson_coefficient(initial_state, final_state)
+    }
+    
+    /// Validates that a phase change happens near the substance's transition temperature
+    ///
+    /// The mean temperature of the two states is compared with the melting point,
+    /// taken as the triple point since the melting curve is nearly vertical, or
+    /// with the boiling or sublimation point at the mean pressure from the
+    /// Clausius–Clapeyron relation. Changes without a tabulated transition pass.
+    /// Returns `NoPhaseCoexistence` when the two phases cannot coexist at that
+    /// pressure: boiling above the critical pressure, or beyond the pressure at
+    /// which the coexistence curve diverges.
+    pub fn validate_transition_temperature(
+        &self,
+        initial_state: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+        substance: &SubstanceProperties,
+    ) -> Result<(), ThermoValidationError> {
+        use PhaseState::*;
         
-        // Calculate entropy using Sackur-Tetrode equation (simplified)
-        let entropy_constant = PhysicalConstants::BOLTZMANN_CONSTANT * 
-            (3.0/2.0 * (2.0 * std::f64::consts::PI * PhysicalConstants::ELECTRON_MASS * 
-            PhysicalConstants::BOLTZMANN_CONSTANT * state.temperature / 
-            PhysicalConstants::PLANCK_CONSTANT.powi(2)).ln() + 5.0/2.0);
-        equilibrium_state.entropy = entropy_constant;
+        let pressure = (initial_state.pressure + final_state.pressure) / 2.0;
+        let triple_point = substance.triple_point_temperature.zip(substance.triple_point_pressure);
+        let no_coexistence = ThermoValidationError::NoPhaseCoexistence { pressure };
         
-        // Calculate Gibbs free energy: G = H - TS
-        equilibrium_state.gibbs_energy = equilibrium_state.enthalpy - 
-            state.temperature * equilibrium_state.entropy;
+        let expected = match (&initial_state.phase, &final_state.phase) {
+            (Solid, Liquid) | (Liquid, Solid) => substance.triple_point_temperature,
+            (Liquid, Gas) | (Gas, Liquid) => {
+                if pressure > substance.critical_pressure {
+                    return Err(no_coexistence);
+                }
+                
+                let (t_ref, p_ref) = triple_point
+                    .unwrap_or((substance.critical_temperature, substance.critical_pressure));
+                let boiling = self.coexistence_temperature(
+                    t_ref,
+                    p_ref,
+                    substance.enthalpy_of_vaporization,
+                    pressure,
+                );
+                Some(boiling.ok_or(no_coexistence)?)
+            }
+            (Solid, Gas) | (Gas, Solid) => match triple_point {
+                Some((t_triple, p_triple)) => {
+                    let sublimation = substance.enthalpy_of_fusion.unwrap_or(0.0) +
+                        substance.enthalpy_of_vaporization;
+                    let sublimation_point =
+                        self.coexistence_temperature(t_triple, p_triple, sublimation, pressure);
+                    Some(sublimation_point.ok_or(no_coexistence)?)
+                }
+                None => None,
+            },
+            _ => None,
+        };
         
-        equilibrium_state
+        let expected = match expected {
+            Some(expected) => expected,
+            None => return Ok(()),
+        };
+        
+        let actual = (initial_state.temperature + final_state.temperature) / 2.0;
+        if (actual - expected).abs() > self.tolerances.transition_temperature * expected {
+            return Err(ThermoValidationError::TransitionTemperatureMismatch { expected, actual });
+        }
+        
+        Ok(())
     }
     
-    /// Batch validate multiple state changes
-    pub fn batch_validate_state_changes(
+    /// Temperature at which a coexistence curve through (t_ref, p_ref) reaches `pressure`
+    ///
+    /// Inverts the Clausius–Clapeyron relation 1/T = 1/T_ref − (R/ΔH) ln(P/P_ref).
+    /// Returns `None` when the right-hand side is not positive, i.e. the curve
+    /// diverges before reaching `pressure`.
+    fn coexistence_temperature(
         &self,
-        state_pairs: &[(ThermodynamicState, ThermodynamicState)],
-    ) -> Vec<ValidationResult> {
-        state_pairs
-            .iter()
-            .map(|(initial, final)| {
-                self.validate_state_change(initial, final)
-                    .unwrap_or_else(|error| ValidationResult {
-                        is_valid: false,
-                        error_message: Some(error.to_string()),
-                        confidence_score: 0.0,
-                        validator_consensus: vec![],
-                        timestamp: std::time::SystemTime::now()
-                            .duration_since(std::time::UNIX_EPOCH)
-                            .unwrap()
-                            .as_secs(),
-                    })
-            })
-            .collect()
+        t_ref: f64,
+        p_ref: f64,
+        enthalpy: f64,
+        pressure: f64,
+    ) -> Option<f64> {
+        let r = self.constants.gas_constant;
+        let inverse = 1.0 / t_ref - r / enthalpy * (pressure / p_ref).ln();
+        (inverse > 0.0).then(|| 1.0 / inverse).filter(|temperature| temperature.is_finite())
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
     
-    #[test]
-    fn test_valid_melting_transition() {
-        let tracker = ThermoStateTracker::new();
+    /// Validates that extracted work does not exceed the Carnot limit between two reservoirs
+    pub fn validate_carnot_bound(
+        &self,
+        work: f64,
+        heat_in: f64,
+        t_cold: f64,
+        t_hot: f64,
+    ) -> Result<(), ThermoValidationError> {
+        if t_cold <= 0.0 || t_hot <= t_cold {
+            return Err(ThermoValidationError::InvalidReservoirTemperatures { t_cold, t_hot });
+        }
         
-        let initial_state = ThermodynamicState {
-            substance_id: "H2O".to_string(),
-            temperature: 273.15,
-            pressure: 101325.0,
-            volume: 1e-3,
-            phase: PhaseState::Solid,
-            entropy: 100.0,
-            enthalpy: 1000.0,
-            gibbs_energy: -500.0,
-            internal_energy: 900.0,
-            timestamp: 1640995200,
-            validated: false,
-        };
+        if heat_in <= 0.0 {
+            return Err(ThermoValidationError::InvalidHeatInput { heat_in });
+        }
         
-        let final_state = ThermodynamicState {
-            substance_id: "H2O".to_string(),
-            temperature: 273.16,
-            pressure: 101325.0,
-            volume: 1e-3,
-            phase: PhaseState::Liquid,
-            entropy: 105.0, // Entropy increases
-            enthalpy: 1334000.0, // Enthalpy of fusion added
-            gibbs_energy: -500.1, // Slightly more negative
-            internal_energy: 1233675.0,
-            timestamp: 1640995260,
-            validated: false,
-        };
+        // η = W/Q_in ≤ 1 − T_cold/T_hot
+        let claimed = work / heat_in;
+        let limit = 1.0 - t_cold / t_hot;
         
-        let result = tracker.validate_state_change(&initial_state, &final_state);
-        assert!(result.is_ok());
-        assert!(result.unwrap().is_valid);
+        if claimed > limit {
+            return Err(ThermoValidationError::CarnotEfficiencyExceeded { claimed, limit });
+        }
+        
+        Ok(())
     }
     
-    #[test]
-    fn test_entropy_decrease_violation() {
-        let tracker = ThermoStateTracker::new();
-        
-        let initial_state = ThermodynamicState {
-            substance_id: "H2O".to_string(),
-            temperature: 373.15,
+    /// Validates that the enthalpy jump across a phase change matches the substance's latent heat
+    ///
+    /// Melting and vaporization must absorb n·ΔH_fus and n·ΔH_vap respectively, and
+    /// freezing and condensation release the same amounts. Other transitions, and
+    /// substances without tabulated data for the transition, are not checked.
+    pub fn validate_latent_heat(
+        &self,
+        initial_state: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+        substance: &SubstanceProperties,
+        n_moles: f64,
+    ) -> Result<(), ThermoValidationError> {
+        use PhaseState::*;
+        
+        if n_moles <= 0.0 {
+            return Err(ThermoValidationError::InvalidMoleCount { n_moles });
+        }
+        
+        let molar_latent_heat = match (&initial_state.phase, &final_state.phase) {
+            (Solid, Liquid) => substance.enthalpy_of_fusion,
+            (Liquid, Solid) => substance.enthalpy_of_fusion.map(|h| -h),
+            (Liquid, Gas) => Some(substance.enthalpy_of_vaporization),
+            (Gas, Liquid) => Some(-substance.enthalpy_of_vaporization),
+            _ => None,
+        };
+        
+        let expected = match molar_latent_heat {
+            Some(molar_latent_heat) => n_moles * molar_latent_heat,
+            None => return Ok(()),
+        };
+        
+        let actual = final_state.enthalpy - initial_state.enthalpy;
+        if (actual - expected).abs() > self.tolerances.latent_heat * expected.abs() {
+            return Err(ThermoValidationError::LatentHeatMismatch { expected, actual });
+        }
+        
+        Ok(())
+    }
+    
+    /// Validates the latent heat of melting, freezing, boiling, or condensing a known substance
+    ///
+    /// The amount of substance is taken from the gas-side state via n = PV/RT
+    /// when there is one, and from `with_amount_of_substance` otherwise.
+    fn validate_phase_change_latent_heat(
+        &self,
+        initial_state: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+    ) -> Result<(), ThermoValidationError> {
+        use PhaseState::*;
+        
+        let gas_side = match (&initial_state.phase, &final_state.phase) {
+            (Solid, Liquid) | (Liquid, Solid) => None,
+            (Liquid, Gas) => Some(final_state),
+            (Gas, Liquid) => Some(initial_state),
+            _ => return Ok(()),
+        };
+        
+        let substance = match self.substances.get(&initial_state.substance_id) {
+            Some(substance) => substance,
+            None => return Ok(()),
+        };
+        
+        let n_moles = match gas_side {
+            Some(gas) => {
+                gas.pressure * gas.volume / (self.constants.gas_constant * gas.temperature)
+            }
+            None => self.amount_of_substance,
+        };
+        
+        self.validate_latent_heat(initial_state, final_state, substance, n_moles)
+    }
+    
+    /// Checks whether the declared phase is the stable one at the state's temperature and pressure
+    ///
+    /// Plasma and Bose–Einstein condensate phases lie outside the substance's
+    /// phase diagram and are always reported as stable.
+    pub fn is_phase_stable(
+        &self,
+        state: &ThermodynamicState,
+        substance: &SubstanceProperties,
+    ) -> bool {
+        match self.stable_phase(state, substance) {
+            Some(stable) => stable == state.phase,
+            None => true,
+        }
+    }
+    
+    /// Validates that the declared phase is the stable one at the state's conditions
+    pub fn validate_phase_stability(
+        &self,
+        state: &ThermodynamicState,
+        substance: &SubstanceProperties,
+    ) -> Result<(), ThermoValidationError> {
+        match self.stable_phase(state, substance) {
+            Some(stable) if stable != state.phase => {
+                Err(ThermoValidationError::PhaseNotStableAtConditions {
+                    declared: state.phase.clone(),
+                    stable,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+    
+    /// Determines the stable phase from a simplified phase diagram
+    ///
+    /// Above both critical values the fluid is supercritical. Otherwise the
+    /// vaporization and sublimation curves are extrapolated from the triple point
+    /// with the Clausius–Clapeyron equation P = P₀exp(−ΔH/R (1/T − 1/T₀)), and the
+    /// melting line is taken as vertical at the triple-point temperature. Substances
+    /// without a triple point never solidify and use the critical point as reference.
+    fn stable_phase(
+        &self,
+        state: &ThermodynamicState,
+        substance: &SubstanceProperties,
+    ) -> Option<PhaseState> {
+        use PhaseState::*;
+        
+        if matches!(state.phase, Plasma | BoseEinsteinCondensate) {
+            return None;
+        }
+        
+        let (t, p) = (state.temperature, state.pressure);
+        if t >= substance.critical_temperature {
+            return Some(if p >= substance.critical_pressure { SuperCritical } else { Gas });
+        }
+        
+        let r = self.constants.gas_constant;
+        let coexistence_pressure = |t_ref: f64, p_ref: f64, enthalpy: f64| {
+            p_ref * (-enthalpy / r * (1.0 / t - 1.0 / t_ref)).exp()
+        };
+        
+        let triple_point = substance.triple_point_temperature.zip(substance.triple_point_pressure);
+        let stable = match triple_point {
+            Some((t_triple, p_triple)) if t < t_triple => {
+                let sublimation = substance.enthalpy_of_fusion.unwrap_or(0.0) +
+                    substance.enthalpy_of_vaporization;
+                if p > coexistence_pressure(t_triple, p_triple, sublimation) { Solid } else { Gas }
+            }
+            Some((t_triple, p_triple)) => {
+                let vaporization = substance.enthalpy_of_vaporization;
+                if p > coexistence_pressure(t_triple, p_triple, vaporization) { Liquid } else { Gas }
+            }
+            None => {
+                let vapor_pressure = coexistence_pressure(
+                    substance.critical_temperature,
+                    substance.critical_pressure,
+                    substance.enthalpy_of_vaporization,
+                );
+                if p > vapor_pressure { Liquid } else { Gas }
+            }
+        };
+        
+        Some(stable)
+    }
+    
+    /// Validates that pressure, volume, and temperature satisfy PV = nRT
+    pub fn validate_ideal_gas_consistency(
+        &self,
+        state: &ThermodynamicState,
+        n_moles: f64,
+    ) -> Result<(), ThermoValidationError> {
+        if n_moles <= 0.0 {
+            return Err(ThermoValidationError::InvalidMoleCount { n_moles });
+        }
+        
+        let expected_pressure = n_moles * self.constants.gas_constant * state.temperature /
+            state.volume;
+        let relative_error = (state.pressure - expected_pressure).abs() / expected_pressure;
+        
+        if relative_error > self.tolerances.ideal_gas {
+            return Err(ThermoValidationError::IdealGasInconsistent {
+                expected_pressure,
+                actual_pressure: state.pressure,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Calculates the compressibility factor Z = PV/(nRT)
+    pub fn compressibility_factor(
+        &self,
+        state: &ThermodynamicState,
+        n_moles: f64,
+    ) -> Result<f64, ThermoValidationError> {
+        if n_moles <= 0.0 {
+            return Err(ThermoValidationError::InvalidMoleCount { n_moles });
+        }
+        
+        if state.temperature <= 0.0 {
+            return Err(ThermoValidationError::InvalidTemperature {
+                temperature: state.temperature,
+            });
+        }
+        
+        Ok(state.pressure * state.volume /
+            (n_moles * self.constants.gas_constant * state.temperature))
+    }
+    
+    /// Checks whether the compressibility factor is within `tolerance` of the ideal-gas value 1
+    pub fn is_near_ideal(&self, state: &ThermodynamicState, n_moles: f64, tolerance: f64) -> bool {
+        matches!(
+            self.compressibility_factor(state, n_moles),
+            Ok(z) if (z - 1.0).abs() <= tolerance
+        )
+    }
+    
+    /// Speed of sound in an ideal gas in m/s: c = √(γRT/M)
+    ///
+    /// `gamma` is the heat capacity ratio and `molar_mass` is in kg/mol.
+    pub fn speed_of_sound(
+        &self,
+        state: &ThermodynamicState,
+        gamma: f64,
+        molar_mass: f64,
+    ) -> Result<f64, ThermoValidationError> {
+        if state.temperature <= 0.0 {
+            return Err(ThermoValidationError::InvalidTemperature {
+                temperature: state.temperature,
+            });
+        }
+        
+        if gamma <= 1.0 {
+            return Err(ThermoValidationError::InvalidHeatCapacityRatio { gamma });
+        }
+        
+        if molar_mass <= 0.0 {
+            return Err(ThermoValidationError::InvalidMolarMass { molar_mass });
+        }
+        
+        Ok((gamma * self.constants.gas_constant * state.temperature / molar_mass).sqrt())
+    }
+    
+    /// Validates that a state is cold enough to form a Bose–Einstein condensate
+    ///
+    /// T_c = (2πℏ²/mk)(n/ζ(3/2))^(2/3) for an ideal Bose gas of particle mass m
+    /// (kg) and number density n (m⁻³).
+    pub fn validate_bec_feasibility(
+        &self,
+        state: &ThermodynamicState,
+        particle_mass: f64,
+        number_density: f64,
+    ) -> Result<(), ThermoValidationError> {
+        if particle_mass <= 0.0 || number_density <= 0.0 {
+            return Err(ThermoValidationError::InvalidBecParameters {
+                particle_mass,
+                number_density,
+            });
+        }
+        
+        const ZETA_3_2: f64 = 2.612375348685488;
+        let hbar = self.constants.planck_constant / (2.0 * std::f64::consts::PI);
+        let critical = 2.0 * std::f64::consts::PI * hbar.powi(2) /
+            (particle_mass * self.constants.boltzmann_constant) *
+            (number_density / ZETA_3_2).powf(2.0 / 3.0);
+        
+        if state.temperature > critical {
+            return Err(ThermoValidationError::BecTemperatureTooHigh {
+                temperature: state.temperature,
+                critical,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Validates that a plasma-phase state is hot enough to be appreciably ionized
+    ///
+    /// The Saha equation n_i/n_0 = (2πm_ekT/h²)^(3/2) e^(−χ/kT) / n_e, with the
+    /// statistical-weight factor of hydrogen (2g_i/g_0 = 1), gives the equilibrium
+    /// ionization fraction x = n_i/(n_i + n_0). A plasma needs at least 1%
+    /// ionization; states in other phases are not checked.
+    pub fn validate_saha_ionization(
+        &self,
+        state: &ThermodynamicState,
+        ionization_energy_ev: f64,
+        electron_density: f64,
+    ) -> Result<(), ThermoValidationError> {
+        if ionization_energy_ev <= 0.0 || electron_density <= 0.0 {
+            return Err(ThermoValidationError::InvalidIonizationParameters {
+                ionization_energy: ionization_energy_ev,
+                electron_density,
+            });
+        }
+        
+        if state.phase != PhaseState::Plasma {
+            return Ok(());
+        }
+        
+        if state.temperature <= 0.0 {
+            return Err(ThermoValidationError::InvalidTemperature {
+                temperature: state.temperature,
+            });
+        }
+        
+        const MIN_PLASMA_IONIZATION: f64 = 1e-2;
+        let kt = self.constants.boltzmann_constant * state.temperature;
+        let chi = ionization_energy_ev * self.constants.electron_charge;
+        let thermal_density = (2.0 * std::f64::consts::PI * self.constants.electron_mass * kt /
+            self.constants.planck_constant.powi(2)).powf(1.5);
+        let ratio = thermal_density * (-chi / kt).exp() / electron_density;
+        let fraction = ratio / (1.0 + ratio);
+        
+        if fraction < MIN_PLASMA_IONIZATION {
+            return Err(ThermoValidationError::IonizationTooLow {
+                fraction,
+                temperature: state.temperature,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Calculates entropy production σ = ΔS_system − Q/T_reservoir in J/K
+    ///
+    /// `heat_exchanged` is the heat absorbed by the system from a reservoir at
+    /// `reservoir_temp`. σ ≈ 0 for a reversible step and σ > 0 for an irreversible one.
+    pub fn entropy_production(
+        &self,
+        initial_state: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+        heat_exchanged: f64,
+        reservoir_temp: f64,
+    ) -> Result<f64, ThermoValidationError> {
+        if reservoir_temp <= 0.0 {
+            return Err(ThermoValidationError::InvalidTemperature {
+                temperature: reservoir_temp,
+            });
+        }
+        
+        let delta_s = final_state.entropy - initial_state.entropy;
+        Ok(delta_s - heat_exchanged / reservoir_temp)
+    }
+    
+    /// Calculates the work done by the system as its volume changes, in J
+    ///
+    /// With `constant_pressure` the process is isobaric and W = P·ΔV; otherwise it
+    /// is treated as an isothermal ideal-gas process with W = nRT·ln(V_f/V_i),
+    /// where nRT is taken from the initial state as P_i·V_i. Expansion gives W > 0.
+    pub fn pv_work(
+        &self,
+        initial_state: &ThermodynamicState,
+        final_state: &ThermodynamicState,
+        constant_pressure: bool,
+    ) -> Result<f64, ThermoValidationError> {
+        for volume in [initial_state.volume, final_state.volume] {
+            if !(volume > 0.0 && volume.is_finite()) {
+                return Err(ThermoValidationError::InvalidVolume { volume });
+            }
+        }
+        
+        if constant_pressure {
+            Ok(initial_state.pressure * (final_state.volume - initial_state.volume))
+        } else {
+            Ok(initial_state.pressure * initial_state.volume *
+                (final_state.volume / initial_state.volume).ln())
+        }
+    }
+    
+    /// Validates an energy transfer between two systems
+    ///
+    /// `source_delta` and `sink_delta` are the energy changes of the two systems and
+    /// `losses` the energy dissipated along the way; together they must sum to zero.
+    pub fn validate_energy_transfer(
+        &self,
+        source_delta: f64,
+        sink_delta: f64,
+        losses: f64,
+    ) -> Result<(), ThermoValidationError> {
+        for (field, value) in [
+            ("source_delta", source_delta),
+            ("sink_delta", sink_delta),
+            ("losses", losses),
+        ] {
+            if !value.is_finite() {
+                return Err(ThermoValidationError::NonFiniteInput { field });
+            }
+        }
+        
+        if losses < 0.0 {
+            return Err(ThermoValidationError::NegativeTransferLosses { losses });
+        }
+        
+        let residual = source_delta + sink_delta + losses;
+        if residual.abs() > self.tolerances.transfer {
+            return Err(ThermoValidationError::EnergyTransferImbalance { residual });
+        }
+        
+        Ok(())
+    }
+    
+    /// Gibbs free energy G = H − TS in J from the state's own enthalpy and entropy
+    pub fn gibbs_energy(&self, state: &ThermodynamicState) -> f64 {
+        state.enthalpy - state.temperature * state.entropy
+    }
+    
+    /// Validates that the stored `gibbs_energy` agrees with H − TS within the Gibbs tolerance
+    pub fn validate_gibbs_consistency(
+        &self,
+        state: &ThermodynamicState,
+    ) -> Result<(), ThermoValidationError> {
+        let computed = self.gibbs_energy(state);
+        
+        if (state.gibbs_energy - computed).abs() > self.tolerances.gibbs {
+            return Err(ThermoValidationError::GibbsFieldInconsistent {
+                stored: state.gibbs_energy,
+                computed,
+            });
+        }
+        
+        Ok(())
+    }
+    
+    /// Calculates equilibrium properties for a given state, assuming a monatomic gas
+    pub fn calculate_equilibrium_properties(
+        &self,
+        state: &ThermodynamicState,
+    ) -> ThermodynamicState {
+        self.calculate_equilibrium_properties_for(state, MoleculeType::Monatomic)
+    }
+    
+    /// Calculates equilibrium properties for a given state of the given molecule type
+    pub fn calculate_equilibrium_properties_for(
+        &self,
+        state: &ThermodynamicState,
+        molecule: MoleculeType,
+    ) -> ThermodynamicState {
+        let mut equilibrium_state = state.clone();
+        
+        // Calculate internal energy using ideal gas approximation
+        equilibrium_state.internal_energy =
+            self.calculate_internal_energy(state, molecule.degrees_of_freedom());
+        
+        // Calculate enthalpy: H = U + P V
+        equilibrium_state.enthalpy = equilibrium_state.internal_energy + 
+            state.pressure * state.volume;
+        
+        // Calculate entropy using Sackur-Tetrode equation (simplified)
+        let entropy_constant = self.constants.boltzmann_constant * 
+            (3.0/2.0 * (2.0 * std::f64::consts::PI * self.constants.electron_mass * 
+            self.constants.boltzmann_constant * state.temperature / 
+            self.constants.planck_constant.powi(2)).ln() + 5.0/2.0);
+        equilibrium_state.entropy = entropy_constant;
+        
+        // Calculate Gibbs free energy: G = H - TS
+        equilibrium_state.gibbs_energy = self.gibbs_energy(&equilibrium_state);
+        
+        equilibrium_state
+    }
+    
+    /// Internal energy of an ideal gas by equipartition: U = (f/2)nRT
+    ///
+    /// `degrees_of_freedom` counts the quadratic modes, e.g. 3 for a monatomic
+    /// gas, 5 for a rigid diatomic, and 7 once its vibration is active. The
+    /// amount of substance is taken from `state` via n = PV/RT, as in
+    /// `interpolate_isotherm`, so nRT is simply PV.
+    pub fn calculate_internal_energy(
+        &self,
+        state: &ThermodynamicState,
+        degrees_of_freedom: u32,
+    ) -> f64 {
+        let n_rt = state.pressure * state.volume;
+        degrees_of_freedom as f64 / 2.0 * n_rt
+    }
+    
+    /// Calculates equilibrium properties together with their first-order uncertainties
+    ///
+    /// Returns the nominal state from `calculate_equilibrium_properties` and a state
+    /// whose fields hold the 1σ uncertainty of each quantity, propagated from the
+    /// temperature and pressure uncertainties through the same ideal-gas formulas.
+    /// The volume is taken as exact.
+    pub fn calculate_equilibrium_properties_with_uncertainty(
+        &self,
+        state: &ThermodynamicState,
+        temp_sigma: f64,
+        pressure_sigma: f64,
+    ) -> (ThermodynamicState, ThermodynamicState) {
+        let nominal = self.calculate_equilibrium_properties(state);
+        
+        // Partial derivatives of U = C_v T, H = U + PV, S(T), and G = H − TS,
+        // holding n = PV/RT fixed so that C_v = 3/2 nR
+        let cv = 1.5 * state.pressure * state.volume / state.temperature;
+        let ds_dt = 1.5 * self.constants.boltzmann_constant / state.temperature;
+        let dg_dt = cv - nominal.entropy - state.temperature * ds_dt;
+        let pressure_term = state.volume * pressure_sigma;
+        
+        let mut sigma = nominal.clone();
+        sigma.temperature = temp_sigma.abs();
+        sigma.pressure = pressure_sigma.abs();
+        sigma.volume = 0.0;
+        sigma.internal_energy = (cv * temp_sigma).abs();
+        sigma.enthalpy = (cv * temp_sigma).hypot(pressure_term);
+        sigma.entropy = (ds_dt * temp_sigma).abs();
+        sigma.gibbs_energy = (dg_dt * temp_sigma).hypot(pressure_term);
+        
+        (nominal, sigma)
+    }
+    
+    /// Generates ideal-gas states along the isotherm through `state` from `p_start` to `p_end`
+    ///
+    /// Returns `steps + 1` evenly spaced states including both end points. The
+    /// amount of substance is taken from `state` via n = PV/RT and each volume
+    /// is recomputed at constant temperature, so PV is the same for every state.
+    pub fn interpolate_isotherm(
+        &self,
+        state: &ThermodynamicState,
+        p_start: f64,
+        p_end: f64,
+        steps: usize,
+    ) -> Result<Vec<ThermodynamicState>, ThermoValidationError> {
+        if steps == 0 {
+            return Err(ThermoValidationError::InvalidStepCount { steps });
+        }
+        
+        for pressure in [state.pressure, p_start, p_end] {
+            if pressure <= 0.0 {
+                return Err(ThermoValidationError::InvalidPressure { pressure });
+            }
+        }
+        
+        if state.temperature <= 0.0 {
+            return Err(ThermoValidationError::InvalidTemperature {
+                temperature: state.temperature,
+            });
+        }
+        
+        let n_moles = state.pressure * state.volume /
+            (self.constants.gas_constant * state.temperature);
+        
+        Ok((0..=steps)
+            .map(|step| {
+                let pressure = p_start + (p_end - p_start) * step as f64 / steps as f64;
+                let mut intermediate = state.clone();
+                intermediate.pressure = pressure;
+                intermediate.volume = n_moles * self.constants.gas_constant * state.temperature /
+                    pressure;
+                self.calculate_equilibrium_properties(&intermediate)
+            })
+            .collect())
+    }
+    
+    /// Batch validate multiple state changes
+    pub fn batch_validate_state_changes(
+        &self,
+        state_pairs: &[(ThermodynamicState, ThermodynamicState)],
+    ) -> Vec<ValidationResult> {
+        state_pairs
+            .iter()
+            .map(|(initial, final_state)| {
+                self.validate_state_change(initial, final_state)
+                    .unwrap_or_else(|error| ValidationResult {
+                        is_valid: false,
+                        error_message: Some(error.to_string()),
+                        confidence_score: 0.0,
+                        validator_consensus: vec![],
+                        timestamp: self.clock.now_secs(),
+                        failed_check: Some(error.failed_check()),
+                        notes: Vec::new(),
+                    })
+            })
+            .inspect(|result| self.notify_failure(result))
+            .collect()
+    }
+    
+    /// Passes a failed result to the registered observer, if any
+    fn notify_failure(&self, result: &ValidationResult) {
+        if result.is_valid {
+            return;
+        }
+        
+        if let Some(observer) = &self.on_failure {
+            observer(result);
+        }
+    }
+}
+
+/// Builds self-consistent thermodynamic states from their independent variables
+///
+/// Entropy, enthalpy, Gibbs energy, and internal energy are derived with the
+/// ideal-gas relations of `ThermoStateTracker::calculate_equilibrium_properties`
+/// rather than supplied by hand. Defaults to one mole of gas at STP. Unless
+/// set explicitly, the volume follows from the ideal gas law V = nRT/P.
+#[derive(Debug, Clone)]
+pub struct ThermodynamicStateBuilder {
+    substance_id: String,
+    temperature: f64,
+    pressure: f64,
+    n_moles: f64,
+    volume: Option<f64>,
+    phase: PhaseState,
+    timestamp: u64,
+}
+
+impl ThermodynamicStateBuilder {
+    pub fn new(substance_id: &str) -> Self {
+        ThermodynamicStateBuilder {
+            substance_id: substance_id.to_string(),
+            temperature: 273.15, // K
+            pressure: 101325.0,  // Pa
+            n_moles: 1.0,        // mol
+            volume: None,        // m³, derived from n, T, and P
+            phase: PhaseState::Gas,
+            timestamp: 0,
+        }
+    }
+    
+    pub fn with_temperature(mut self, temperature: f64) -> Self {
+        self.temperature = temperature;
+        self
+    }
+    
+    pub fn with_pressure(mut self, pressure: f64) -> Self {
+        self.pressure = pressure;
+        self
+    }
+    
+    pub fn with_moles(mut self, n_moles: f64) -> Self {
+        self.n_moles = n_moles;
+        self
+    }
+    
+    /// Fixes the volume instead of deriving it; `build` checks it against PV = nRT
+    pub fn with_volume(mut self, volume: f64) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+    
+    pub fn with_phase(mut self, phase: PhaseState) -> Self {
+        self.phase = phase;
+        self
+    }
+    
+    pub fn with_timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+    
+    /// Derives the volume and energy fields with `tracker`'s constants
+    ///
+    /// Rejects non-finite or unphysical inputs, and an explicit volume that
+    /// does not satisfy PV = nRT within the ideal-gas tolerance.
+    pub fn build(
+        self,
+        tracker: &ThermoStateTracker,
+    ) -> Result<ThermodynamicState, ThermoValidationError> {
+        if self.n_moles <= 0.0 {
+            return Err(ThermoValidationError::InvalidMoleCount { n_moles: self.n_moles });
+        }
+        
+        let derived_volume = self.n_moles * tracker.constants.gas_constant * self.temperature /
+            self.pressure;
+        let state = ThermodynamicState {
+            substance_id: self.substance_id,
+            temperature: self.temperature,
+            pressure: self.pressure,
+            volume: self.volume.unwrap_or(derived_volume),
+            phase: self.phase,
+            entropy: 0.0,
+            enthalpy: 0.0,
+            gibbs_energy: 0.0,
+            internal_energy: 0.0,
+            timestamp: self.timestamp,
+            validated: false,
+            population_inverted: false,
+        };
+        
+        tracker.validate_finite_inputs(&state)?;
+        tracker.validate_physical_constraints(&state)?;
+        if self.volume.is_some() {
+            tracker.validate_ideal_gas_consistency(&state, self.n_moles)?;
+        }
+        
+        Ok(tracker.calculate_equilibrium_properties(&state))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ConfidenceWeights, FixedClock, PhysicalConstants};
+    
+    fn gas_state(
+        temperature: f64,
+        pressure: f64,
+        volume: f64,
+        entropy: f64,
+        enthalpy: f64,
+    ) -> ThermodynamicState {
+        ThermodynamicState {
+            substance_id: "N2".to_string(),
+            temperature,
+            pressure,
+            volume,
+            phase: PhaseState::Gas,
+            entropy,
+            enthalpy,
+            gibbs_energy: enthalpy - temperature * entropy,
+            internal_energy: enthalpy - pressure * volume,
+            timestamp: 1640995200,
+            validated: false,
+            population_inverted: false,
+        }
+    }
+    
+    #[test]
+    fn test_valid_melting_transition() {
+        let tracker = ThermoStateTracker::new();
+        
+        let initial_state = ThermodynamicState {
+            substance_id: "H2O".to_string(),
+            temperature: 273.15,
+            pressure: 101325.0,
+            volume: 1e-3,
+            phase: PhaseState::Solid,
+            entropy: 100.0,
+            enthalpy: 1000.0,
+            gibbs_energy: -500.0,
+            internal_energy: 900.0,
+            timestamp: 1640995200,
+            validated: false,
+            population_inverted: false,
+        };
+        
+        let final_state = ThermodynamicState {
+            substance_id: "H2O".to_string(),
+            temperature: 273.16,
+            pressure: 101325.0,
+            volume: 1e-3,
+            phase: PhaseState::Liquid,
+            entropy: 105.0, // Entropy increases
+            enthalpy: 1334000.0, // Enthalpy of fusion added
+            gibbs_energy: -500.1, // Slightly more negative
+            internal_energy: 1233675.0,
+            timestamp: 1640995260,
+            validated: false,
+            population_inverted: false,
+        };
+        
+        let result = tracker.validate_state_change(&initial_state, &final_state);
+        assert!(result.is_ok());
+        assert!(result.unwrap().is_valid);
+    }
+    
+    #[test]
+    fn test_entropy_decrease_violation() {
+        let tracker = ThermoStateTracker::new();
+        
+        let initial_state = ThermodynamicState {
+            substance_id: "H2O".to_string(),
+            temperature: 373.15,
             pressure: 101325.0,
             volume: 1e-3,
             phase: PhaseState::Gas,
@@ -308,23 +1667,1050 @@ mod tests {
             internal_energy: 1800.0,
             timestamp: 1640995200,
             validated: false,
+            population_inverted: false,
+        };
+        
+        let final_state = ThermodynamicState {
+            substance_id: "H2O".to_string(),
+            temperature: 273.15,
+            pressure: 101325.0,
+            volume: 1e-3,
+            phase: PhaseState::Liquid,
+            entropy: 150.0, // Entropy decreases - violation!
+            enthalpy: 1000.0,
+            gibbs_energy: -500.0,
+            internal_energy: 900.0,
+            timestamp: 1640995260,
+            validated: false,
+            population_inverted: false,
+        };
+        
+        let result = tracker.validate_state_change(&initial_state, &final_state);
+        assert!(result.is_err());
+    }
+    
+    #[test]
+    fn test_infinite_temperature_rejected() {
+        let tracker = ThermoStateTracker::new();
+        
+        let initial_state = ThermodynamicState {
+            substance_id: "N2".to_string(),
+            temperature: 300.0,
+            pressure: 101325.0,
+            volume: 0.0246,
+            phase: PhaseState::Gas,
+            entropy: 191.6,
+            enthalpy: 8700.0,
+            gibbs_energy: -48780.0,
+            internal_energy: 6200.0,
+            timestamp: 1640995200,
+            validated: false,
+            population_inverted: false,
+        };
+        
+        let mut final_state = initial_state.clone();
+        final_state.temperature = f64::INFINITY;
+        final_state.timestamp = 1640995260;
+        
+        let result = tracker.validate_state_change(&initial_state, &final_state);
+        assert!(matches!(
+            result,
+            Err(ThermoValidationError::NonFiniteInput { field: "temperature" })
+        ));
+    }
+    
+    #[test]
+    fn test_isentropic_process_rejects_entropy_jump() {
+        let tracker = ThermoStateTracker::new();
+        
+        let initial_state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        let compressed = gas_state(390.0, 202650.0, 0.0160, 191.6, 8600.0);
+        let jumped = gas_state(390.0, 202650.0, 0.0160, 195.0, 8600.0);
+        
+        let result = tracker.validate_state_change_with_constraint(
+            &initial_state,
+            &compressed,
+            Some(ProcessConstraint::Isentropic),
+        );
+        assert!(result.is_ok());
+        
+        let result = tracker.validate_state_change_with_constraint(
+            &initial_state,
+            &jumped,
+            Some(ProcessConstraint::Isentropic),
+        );
+        assert!(matches!(
+            result,
+            Err(ThermoValidationError::ProcessConstraintViolated {
+                constraint: ProcessConstraint::Isentropic
+            })
+        ));
+    }
+    
+    #[test]
+    fn test_isothermal_and_isochoric_constraints() {
+        let tracker = ThermoStateTracker::new();
+        
+        let initial_state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        let expanded = gas_state(300.0, 50662.5, 0.0492, 197.4, 8700.0);
+        
+        let result = tracker.validate_state_change_with_constraint(
+            &initial_state,
+            &expanded,
+            Some(ProcessConstraint::Isothermal),
+        );
+        assert!(result.is_ok());
+        
+        let result = tracker.validate_state_change_with_constraint(
+            &initial_state,
+            &expanded,
+            Some(ProcessConstraint::Isochoric),
+        );
+        assert!(matches!(
+            result,
+            Err(ThermoValidationError::ProcessConstraintViolated {
+                constraint: ProcessConstraint::Isochoric
+            })
+        ));
+    }
+    
+    #[test]
+    fn test_adiabatic_constraint() {
+        let tracker = ThermoStateTracker::new();
+        
+        // One mole of monatomic ideal gas: U = 3/2 RT, H = 5/2 RT
+        let monatomic = |temperature: f64, volume: f64, entropy: f64| {
+            let rt = PhysicalConstants::GAS_CONSTANT * temperature;
+            ThermodynamicState {
+                substance_id: "He".to_string(),
+                temperature,
+                pressure: rt / volume,
+                volume,
+                phase: PhaseState::Gas,
+                entropy,
+                enthalpy: 2.5 * rt,
+                gibbs_energy: 2.5 * rt - temperature * entropy,
+                internal_energy: 1.5 * rt,
+                timestamp: 1640995200,
+                validated: false,
+                population_inverted: false,
+            }
+        };
+        
+        // Reversible expansion to twice the volume keeps T·V^(2/3) constant
+        let initial_volume = PhysicalConstants::GAS_CONSTANT * 300.0 / 101325.0;
+        let initial_state = monatomic(300.0, initial_volume, 126.0);
+        let expanded = monatomic(300.0 / 2f64.powf(2.0 / 3.0), 2.0 * initial_volume, 126.0);
+        
+        let result = tracker.validate_state_change_with_constraint(
+            &initial_state,
+            &expanded,
+            Some(ProcessConstraint::Adiabatic),
+        );
+        assert!(result.is_ok());
+        
+        // A free expansion is adiabatic but irreversible: T is unchanged and S rises by R ln 2
+        let free_expansion = monatomic(
+            300.0,
+            2.0 * initial_volume,
+            126.0 + PhysicalConstants::GAS_CONSTANT * 2f64.ln(),
+        );
+        let insulated = HeatExchange { heat: 0.0, reservoir_temperature: 300.0 };
+        let heated = HeatExchange { heat: 500.0, reservoir_temperature: 300.0 };
+        
+        let result = tracker.validate_state_change_with_constraint(
+            &initial_state,
+            &free_expansion,
+            Some(ProcessConstraint::Adiabatic),
+        );
+        assert!(matches!(
+            result,
+            Err(ThermoValidationError::ProcessConstraintViolated {
+                constraint: ProcessConstraint::Adiabatic
+            })
+        ));
+        
+        let result = tracker.validate_adiabatic_state_change(&initial_state, &free_expansion, &insulated);
+        assert!(result.is_ok());
+        
+        let result = tracker.validate_adiabatic_state_change(&initial_state, &free_expansion, &heated);
+        assert!(matches!(
+            result,
+            Err(ThermoValidationError::ProcessConstraintViolated {
+                constraint: ProcessConstraint::Adiabatic
+            })
+        ));
+    }
+    
+    #[test]
+    fn test_carnot_bound() {
+        let tracker = ThermoStateTracker::new();
+        
+        // Limit between 300 K and 600 K is 50%
+        assert!(tracker.validate_carnot_bound(300.0, 1000.0, 300.0, 600.0).is_ok());
+        
+        let result = tracker.validate_carnot_bound(600.0, 1000.0, 300.0, 600.0);
+        assert!(matches!(
+            result,
+            Err(ThermoValidationError::CarnotEfficiencyExceeded { .. })
+        ));
+        
+        let result = tracker.validate_carnot_bound(300.0, 1000.0, 600.0, 300.0);
+        assert!(matches!(
+            result,
+            Err(ThermoValidationError::InvalidReservoirTemperatures { .. })
+        ));
+    }
+    
+    #[test]
+    fn test_ideal_gas_consistency() {
+        let tracker = ThermoStateTracker::new();
+        
+        // One mole at STP occupies 22.414 L
+        let stp = gas_state(273.15, 101325.0, 0.022414, 191.6, 8700.0);
+        assert!(tracker.validate_ideal_gas_consistency(&stp, 1.0).is_ok());
+        
+        let inconsistent = gas_state(273.15, 101325.0, 0.001, 191.6, 8700.0);
+        let result = tracker.validate_ideal_gas_consistency(&inconsistent, 1.0);
+        assert!(matches!(
+            result,
+            Err(ThermoValidationError::IdealGasInconsistent { .. })
+        ));
+    }
+    
+    #[test]
+    fn test_batch_records_failed_check() {
+        let tracker = ThermoStateTracker::new();
+        
+        let initial_state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        let final_state = gas_state(300.0, 101325.0, 0.0246, 150.0, 8700.0);
+        
+        let results = tracker.batch_validate_state_changes(&[(initial_state, final_state)]);
+        assert_eq!(results[0].failed_check, Some(FailedCheck::EntropyDecrease));
+    }
+    
+    #[test]
+    fn test_classify_spontaneity() {
+        let tracker = ThermoStateTracker::new();
+        let initial_state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        
+        // ΔG = ΔH - TΔS with ΔS = 0 reduces to ΔH
+        let exergonic = gas_state(300.0, 101325.0, 0.0246, 191.6, 8600.0);
+        let endergonic = gas_state(300.0, 101325.0, 0.0246, 191.6, 8800.0);
+        let balanced = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0005);
+        
+        assert_eq!(tracker.classify_spontaneity(&initial_state, &exergonic), Spontaneity::Spontaneous);
+        assert_eq!(tracker.classify_spontaneity(&initial_state, &endergonic), Spontaneity::NonSpontaneous);
+        assert_eq!(tracker.classify_spontaneity(&initial_state, &balanced), Spontaneity::Equilibrium);
+    }
+    
+    #[test]
+    fn test_relative_entropy_tolerance_for_large_system() {
+        let initial_state = gas_state(300.0, 101325.0, 0.0246, 5000.0, 8700.0);
+        let fluctuated = gas_state(300.0, 101325.0, 0.0246, 4999.999, 8699.0);
+        
+        let absolute = ThermoStateTracker::new();
+        let result = absolute.validate_state_change(&initial_state, &fluctuated);
+        assert!(matches!(result, Err(ThermoValidationError::EntropyDecrease { .. })));
+        
+        let relative = ThermoStateTracker::new().with_entropy_tolerance_mode(ToleranceMode::Relative);
+        let result = relative.validate_state_change(&initial_state, &fluctuated);
+        assert!(result.is_ok());
+    }
+    
+    #[test]
+    fn test_relative_entropy_tolerance_for_isentropic_constraint() {
+        let initial_state = gas_state(300.0, 101325.0, 0.0246, 5000.0, 8700.0);
+        let compressed = gas_state(390.0, 202650.0, 0.0160, 5000.001, 8600.0);
+        
+        let absolute = ThermoStateTracker::new();
+        let result = absolute.validate_state_change_with_constraint(
+            &initial_state,
+            &compressed,
+            Some(ProcessConstraint::Isentropic),
+        );
+        assert!(matches!(
+            result,
+            Err(ThermoValidationError::ProcessConstraintViolated {
+                constraint: ProcessConstraint::Isentropic
+            })
+        ));
+        
+        let relative = ThermoStateTracker::new().with_entropy_tolerance_mode(ToleranceMode::Relative);
+        let result = relative.validate_state_change_with_constraint(
+            &initial_state,
+            &compressed,
+            Some(ProcessConstraint::Isentropic),
+        );
+        assert!(result.is_ok());
+    }
+    
+    #[test]
+    fn test_compressibility_factor() {
+        let tracker = ThermoStateTracker::new();
+        
+        // Dilute gas: one mole at 300 K and 1 kPa
+        let dilute_volume = PhysicalConstants::GAS_CONSTANT * 300.0 / 1000.0;
+        let dilute = gas_state(300.0, 1000.0, dilute_volume, 191.6, 8700.0);
+        let z = tracker.compressibility_factor(&dilute, 1.0).unwrap();
+        assert!((z - 1.0).abs() < 1e-9);
+        assert!(tracker.is_near_ideal(&dilute, 1.0, 0.01));
+        
+        // CO₂ near its critical point (304.13 K, 7.38 MPa, 94 cm³/mol) has Z ≈ 0.27
+        let critical = gas_state(304.13, 7.38e6, 9.4e-5, 100.0, 8700.0);
+        let z = tracker.compressibility_factor(&critical, 1.0).unwrap();
+        assert!((z - 0.274).abs() < 0.01);
+        assert!(!tracker.is_near_ideal(&critical, 1.0, 0.05));
+        
+        assert!(tracker.compressibility_factor(&dilute, 0.0).is_err());
+    }
+    
+    #[test]
+    fn test_validate_clean_path() {
+        let tracker = ThermoStateTracker::new();
+        
+        let path = vec![
+            gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0),
+            gas_state(300.0, 90000.0, 0.0277, 192.6, 8690.0),
+            gas_state(300.0, 80000.0, 0.0312, 193.6, 8680.0),
+            gas_state(300.0, 70000.0, 0.0356, 194.6, 8670.0),
+        ];
+        
+        let result = tracker.validate_path(&path).unwrap();
+        assert!(result.is_valid);
+        assert!(result.confidence_score > 0.0 && result.confidence_score <= 1.0);
+    }
+    
+    #[test]
+    fn test_validate_path_reports_bad_middle_step() {
+        let tracker = ThermoStateTracker::new();
+        
+        // Net entropy rises, but the second step decreases it
+        let path = vec![
+            gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0),
+            gas_state(300.0, 90000.0, 0.0277, 195.0, 8690.0),
+            gas_state(300.0, 80000.0, 0.0312, 193.0, 8680.0),
+            gas_state(300.0, 70000.0, 0.0356, 196.0, 8670.0),
+        ];
+        
+        let result = tracker.validate_path(&path);
+        match result {
+            Err(ThermoValidationError::PathStepViolation { step, source }) => {
+                assert_eq!(step, 1);
+                assert!(matches!(*source, ThermoValidationError::EntropyDecrease { .. }));
+            }
+            other => panic!("expected a path step violation, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_failure_observer_counts_failures() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        
+        let failures = Arc::new(AtomicUsize::new(0));
+        let observed = Arc::clone(&failures);
+        let tracker = ThermoStateTracker::new().with_failure_observer(move |_| {
+            observed.fetch_add(1, Ordering::SeqCst);
+        });
+        
+        let initial_state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        let expanded = gas_state(300.0, 50662.5, 0.0492, 197.4, 8700.0);
+        let decreased = gas_state(300.0, 101325.0, 0.0246, 150.0, 8700.0);
+        
+        tracker.batch_validate_state_changes(&[
+            (initial_state.clone(), expanded),
+            (initial_state, decreased),
+        ]);
+        assert_eq!(failures.load(Ordering::SeqCst), 1);
+    }
+    
+    #[test]
+    fn test_rubidium_bec_feasibility() {
+        let tracker = ThermoStateTracker::new();
+        
+        // Rubidium-87 at 10¹⁴ cm⁻³ condenses below roughly 400 nK
+        let rubidium_mass = 86.909 * 1.66053907e-27;
+        let number_density = 1e20;
+        
+        let mut state = gas_state(100e-9, 1e-9, 1.0, 1e-3, 0.0);
+        state.phase = PhaseState::BoseEinsteinCondensate;
+        assert!(tracker.validate_bec_feasibility(&state, rubidium_mass, number_density).is_ok());
+        
+        state.temperature = 1e-6;
+        let result = tracker.validate_bec_feasibility(&state, rubidium_mass, number_density);
+        match result {
+            Err(ThermoValidationError::BecTemperatureTooHigh { critical, .. }) => {
+                assert!((critical - 4.0e-7).abs() < 0.3e-7);
+            }
+            other => panic!("expected BEC temperature error, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_non_si_state_validates_like_si_state() {
+        use crate::types::{Pressure, Temperature};
+        
+        let tracker = ThermoStateTracker::new();
+        
+        let si_state = gas_state(273.15, 101325.0, 0.0224, 191.6, 8700.0);
+        let mut converted_state = si_state.clone();
+        converted_state.temperature = Temperature::Celsius(0.0).kelvin().unwrap();
+        converted_state.pressure = Pressure::Atmosphere(1.0).pascal();
+        
+        assert_eq!(converted_state.temperature, 273.15);
+        assert_eq!(converted_state.pressure, 101325.0);
+        
+        let final_state = gas_state(273.15, 101325.0, 0.0224, 192.0, 8690.0);
+        let si_result = tracker.validate_state_change(&si_state, &final_state).unwrap();
+        let converted_result = tracker.validate_state_change(&converted_state, &final_state).unwrap();
+        assert_eq!(si_result.confidence_score, converted_result.confidence_score);
+        
+        assert!(Temperature::Celsius(-300.0).kelvin().is_err());
+    }
+    
+    #[test]
+    fn test_confidence_precision_rounds_reported_score() {
+        // Quadrupling the temperature triggers the 0.8 extreme-change penalty
+        let initial_state = gas_state(100.0, 101325.0, 0.0246, 191.6, 8700.0);
+        let heated = gas_state(400.0, 101325.0, 0.0984, 220.0, 8700.0);
+        
+        let default_result = ThermoStateTracker::new()
+            .validate_state_change(&initial_state, &heated)
+            .unwrap();
+        assert_eq!(default_result.confidence_score, 0.8);
+        
+        let coarse_result = ThermoStateTracker::new()
+            .with_confidence_precision(ConfidencePrecision { decimal_places: 0 })
+            .validate_state_change(&initial_state, &heated)
+            .unwrap();
+        assert_eq!(coarse_result.confidence_score, 1.0);
+    }
+    
+    #[test]
+    fn test_entropy_production() {
+        let tracker = ThermoStateTracker::new();
+        
+        // Reversible isothermal expansion: ΔS = Q/T with the reservoir at the system temperature
+        let initial_state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        let expanded = gas_state(300.0, 50662.5, 0.0492, 197.363, 8700.0);
+        let heat = 300.0 * (197.363 - 191.6);
+        
+        let sigma = tracker.entropy_production(&initial_state, &expanded, heat, 300.0).unwrap();
+        assert!(sigma.abs() < 1e-9);
+        
+        // The same heat drawn from a hotter reservoir is irreversible
+        let sigma = tracker.entropy_production(&initial_state, &expanded, heat, 600.0).unwrap();
+        assert!(sigma > 0.0);
+        
+        assert!(tracker.entropy_production(&initial_state, &expanded, heat, 0.0).is_err());
+    }
+    
+    #[test]
+    fn test_energy_transfer() {
+        let tracker = ThermoStateTracker::new();
+        
+        // 1000 J leaves the source, 950 J reaches the sink and 50 J is dissipated
+        assert!(tracker.validate_energy_transfer(-1000.0, 950.0, 50.0).is_ok());
+        
+        let result = tracker.validate_energy_transfer(-1000.0, 900.0, 50.0);
+        assert!(matches!(result, Err(ThermoValidationError::EnergyTransferImbalance { .. })));
+        
+        let result = tracker.validate_energy_transfer(-1000.0, 1050.0, -50.0);
+        assert!(matches!(result, Err(ThermoValidationError::NegativeTransferLosses { .. })));
+    }
+    
+    #[test]
+    fn test_bec_formation_flag() {
+        let bec = PhaseState::BoseEinsteinCondensate;
+        
+        let tracker = ThermoStateTracker::new();
+        let result = tracker.validate_phase_transition(&PhaseState::Gas, &bec);
+        assert!(matches!(result, Err(ThermoValidationError::InvalidPhaseTransition { .. })));
+        
+        let tracker = ThermoStateTracker::new().with_bec_formation(true);
+        assert!(tracker.validate_phase_transition(&PhaseState::Gas, &bec).is_ok());
+        assert!(tracker.validate_phase_transition(&PhaseState::Liquid, &bec).is_ok());
+        assert!(tracker.validate_phase_transition(&PhaseState::Plasma, &bec).is_err());
+    }
+    
+    #[test]
+    fn test_latent_heat_of_water() {
+        let tracker = ThermoStateTracker::new();
+        let water = crate::utils::builtin_substances().remove("H2O").unwrap();
+        
+        let phase_state = |phase: PhaseState, temperature: f64, enthalpy: f64| {
+            let mut state = gas_state(temperature, 101325.0, 1.8e-5, 0.0, enthalpy);
+            state.substance_id = "H2O".to_string();
+            state.phase = phase;
+            state
         };
         
-        let final_state = ThermodynamicState {
-            substance_id: "H2O".to_string(),
-            temperature: 273.15,
-            pressure: 101325.0,
-            volume: 1e-3,
-            phase: PhaseState::Liquid,
-            entropy: 150.0, // Entropy decreases - violation!
-            enthalpy: 1000.0,
-            gibbs_energy: -500.0,
-            internal_energy: 900.0,
-            timestamp: 1640995260,
-            validated: false,
+        // Melting one mole of ice absorbs about 6.01 kJ
+        let ice = phase_state(PhaseState::Solid, 273.15, 0.0);
+        let melt = phase_state(PhaseState::Liquid, 273.15, 6010.0);
+        assert!(tracker.validate_latent_heat(&ice, &melt, &water, 1.0).is_ok());
+        assert!(tracker.validate_latent_heat(&melt, &ice, &water, 1.0).is_ok());
+        
+        // Boiling two moles absorbs about 81.3 kJ
+        let liquid = phase_state(PhaseState::Liquid, 373.15, 0.0);
+        let steam = phase_state(PhaseState::Gas, 373.15, 81320.0);
+        assert!(tracker.validate_latent_heat(&liquid, &steam, &water, 2.0).is_ok());
+        
+        let result = tracker.validate_latent_heat(&liquid, &steam, &water, 1.0);
+        match result {
+            Err(ThermoValidationError::LatentHeatMismatch { expected, actual }) => {
+                assert_eq!(expected, 40660.0);
+                assert_eq!(actual, 81320.0);
+            }
+            other => panic!("expected latent heat mismatch, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_state_change_checks_latent_heat() {
+        let tracker = ThermoStateTracker::new();
+        
+        // Entropy rises by ΔH/T from a common reference, so every step keeps ΔG at zero
+        let phase_state = |phase: PhaseState, temperature: f64, volume: f64, enthalpy: f64| {
+            let entropy = 41.0 + enthalpy / temperature;
+            let mut state = gas_state(temperature, 101325.0, volume, entropy, enthalpy);
+            state.substance_id = "H2O".to_string();
+            state.phase = phase;
+            state
         };
         
-        let result = tracker.validate_state_change(&initial_state, &final_state);
-        assert!(result.is_err());
+        // Melting one mole at 273.16 K absorbs about 6.01 kJ
+        let ice = phase_state(PhaseState::Solid, 273.16, 1.963e-5, 0.0);
+        let melt = phase_state(PhaseState::Liquid, 273.16, 1.8e-5, 6010.0);
+        assert!(tracker.validate_state_change(&ice, &melt).is_ok());
+        
+        let result = ThermoStateTracker::new()
+            .with_amount_of_substance(2.0)
+            .validate_state_change(&ice, &melt);
+        assert!(matches!(result, Err(ThermoValidationError::LatentHeatMismatch { .. })));
+        
+        // Boiling: the steam's PV/RT gives one mole, which must absorb about 40.66 kJ
+        let steam_volume = PhysicalConstants::GAS_CONSTANT * 373.15 / 101325.0;
+        let liquid = phase_state(PhaseState::Liquid, 373.15, 1.8e-5, 0.0);
+        let steam = phase_state(PhaseState::Gas, 373.15, steam_volume, 40660.0);
+        assert!(tracker.validate_state_change(&liquid, &steam).is_ok());
+        
+        let superheated = phase_state(PhaseState::Gas, 373.15, steam_volume, 80000.0);
+        match tracker.validate_state_change(&liquid, &superheated) {
+            Err(error @ ThermoValidationError::LatentHeatMismatch { .. }) => {
+                assert_eq!(error.failed_check(), FailedCheck::LatentHeat);
+            }
+            other => panic!("expected latent heat mismatch, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_reversibility_affects_confidence() {
+        let tracker = ThermoStateTracker::new();
+        let initial_state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        let expanded = gas_state(300.0, 50662.5, 0.0492, 197.363, 8700.0);
+        
+        // Reversible isothermal expansion: all entropy change comes from heat
+        let reversible = HeatExchange {
+            heat: 300.0 * (197.363 - 191.6),
+            reservoir_temperature: 300.0,
+        };
+        // Free expansion into vacuum: no heat, all entropy change is generated
+        let free_expansion = HeatExchange {
+            heat: 0.0,
+            reservoir_temperature: 300.0,
+        };
+        
+        let reversible_result = tracker
+            .validate_state_change_with_heat_exchange(&initial_state, &expanded, &reversible)
+            .unwrap();
+        let irreversible_result = tracker
+            .validate_state_change_with_heat_exchange(&initial_state, &expanded, &free_expansion)
+            .unwrap();
+        let plain_result = tracker.validate_state_change(&initial_state, &expanded).unwrap();
+        
+        assert_eq!(reversible_result.confidence_score, plain_result.confidence_score);
+        assert!(irreversible_result.confidence_score < reversible_result.confidence_score);
+    }
+    
+    #[test]
+    fn test_custom_tolerances_take_effect() {
+        let initial_state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        let final_state = gas_state(300.0, 101325.0, 0.0246, 191.6 - 1e-3, 8700.0);
+        
+        let result = ThermoStateTracker::new().validate_state_change(&initial_state, &final_state);
+        assert!(matches!(result, Err(ThermoValidationError::EntropyDecrease { .. })));
+        
+        let tolerances = Tolerances { entropy: 1e-2, gibbs: 1.0, ..Tolerances::default() };
+        let tracker = ThermoStateTracker::new().with_tolerances(tolerances);
+        assert!(tracker.validate_state_change(&initial_state, &final_state).is_ok());
+    }
+    
+    #[test]
+    fn test_validate_state_change_owned_sets_flag() {
+        let tracker = ThermoStateTracker::new();
+        let initial_state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        
+        let heated = gas_state(350.0, 101325.0, 0.0287, 196.1, 10150.0);
+        let validated = tracker.validate_state_change_owned(&initial_state, heated).unwrap();
+        assert!(validated.validated);
+        
+        let entropy_loss = gas_state(300.0, 101325.0, 0.0246, 150.0, 8700.0);
+        assert!(tracker.validate_state_change_owned(&initial_state, entropy_loss.clone()).is_err());
+        assert!(!entropy_loss.validated);
+    }
+    
+    #[test]
+    fn test_joule_thomson_throttling() {
+        let tracker = ThermoStateTracker::new();
+        
+        // Nitrogen throttled from 20 bar to 1 bar at room temperature cools by about 4 K
+        let upstream = gas_state(300.0, 20e5, 1.25e-3, 160.0, 8700.0);
+        let downstream = gas_state(296.0, 1e5, 24.6e-3, 185.0, 8700.0);
+        let mu = tracker.validate_joule_thomson_expansion(&upstream, &downstream).unwrap();
+        assert!(mu > 0.0);
+        assert!((mu - 2.1e-6).abs() < 0.1e-6);
+        
+        // Helium above its inversion temperature warms instead
+        let upstream = gas_state(300.0, 20e5, 1.25e-3, 100.0, 6200.0);
+        let downstream = gas_state(301.2, 1e5, 25.0e-3, 160.0, 6200.0);
+        let mu = tracker.validate_joule_thomson_expansion(&upstream, &downstream).unwrap();
+        assert!(mu < 0.0);
+        
+        let leaky = gas_state(296.0, 1e5, 24.6e-3, 185.0, 8000.0);
+        let result = tracker.validate_joule_thomson_expansion(&upstream, &leaky);
+        assert!(matches!(result, Err(ThermoValidationError::EnthalpyNotConserved { .. })));
+        
+        let result = tracker.joule_thomson_coefficient(&upstream, &upstream);
+        assert!(matches!(result, Err(ThermoValidationError::NoPressureChange { .. })));
+    }
+    
+    #[test]
+    fn test_built_state_is_self_consistent() {
+        let tracker = ThermoStateTracker::new();
+        let state = ThermodynamicStateBuilder::new("He")
+            .with_temperature(300.0)
+            .build(&tracker)
+            .unwrap();
+        
+        assert!(tracker.validate_ideal_gas_consistency(&state, 1.0).is_ok());
+        assert!((state.enthalpy - (state.internal_energy + state.pressure * state.volume)).abs() < 1e-9);
+        assert!((state.gibbs_energy - (state.enthalpy - state.temperature * state.entropy)).abs() < 1e-9);
+        
+        let two_moles = ThermodynamicStateBuilder::new("He")
+            .with_temperature(300.0)
+            .with_moles(2.0)
+            .build(&tracker)
+            .unwrap();
+        assert!(tracker.validate_ideal_gas_consistency(&two_moles, 2.0).is_ok());
+        
+        // The STP molar volume is inconsistent at 300 K
+        let result = ThermodynamicStateBuilder::new("He")
+            .with_temperature(300.0)
+            .with_volume(0.022414)
+            .build(&tracker);
+        assert!(matches!(result, Err(ThermoValidationError::IdealGasInconsistent { .. })));
+        
+        let result = ThermodynamicStateBuilder::new("He").with_temperature(-1.0).build(&tracker);
+        assert!(matches!(result, Err(ThermoValidationError::TemperatureBelowAbsoluteZero { .. })));
+        
+        let result = ThermodynamicStateBuilder::new("He").with_moles(0.0).build(&tracker);
+        assert!(matches!(result, Err(ThermoValidationError::InvalidMoleCount { .. })));
+    }
+    
+    #[test]
+    fn test_temporal_ordering() {
+        let tracker = ThermoStateTracker::new();
+        let states: Vec<_> = (0..3)
+            .map(|step| {
+                let mut state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+                state.timestamp = 1640995200 + step * 60;
+                state
+            })
+            .collect();
+        assert!(tracker.validate_temporal_ordering(&states).is_ok());
+        
+        let mut replayed = states.clone();
+        replayed[2].timestamp = replayed[0].timestamp;
+        let result = tracker.validate_temporal_ordering(&replayed);
+        match result {
+            Err(ThermoValidationError::NonMonotonicTimestamp { index, prev, curr }) => {
+                assert_eq!(index, 2);
+                assert_eq!(prev, 1640995260);
+                assert_eq!(curr, 1640995200);
+            }
+            other => panic!("expected non-monotonic timestamp, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_saha_hydrogen_ionization() {
+        let tracker = ThermoStateTracker::new();
+        let electron_density = 1e20;
+        
+        // Hydrogen at 10 000 K and n_e = 10²⁰ m⁻³ is mostly ionized
+        let mut state = gas_state(10000.0, 1e3, 1.0, 200.0, 2e5);
+        state.phase = PhaseState::Plasma;
+        assert!(tracker.validate_saha_ionization(&state, 13.6, electron_density).is_ok());
+        
+        // At 5000 K the Saha fraction is around 10⁻⁷
+        state.temperature = 5000.0;
+        let result = tracker.validate_saha_ionization(&state, 13.6, electron_density);
+        match result {
+            Err(ThermoValidationError::IonizationTooLow { fraction, .. }) => {
+                assert!(fraction < 1e-6);
+            }
+            other => panic!("expected ionization error, got {:?}", other),
+        }
+        
+        // Neutral gas makes no ionization claim
+        state.phase = PhaseState::Gas;
+        assert!(tracker.validate_saha_ionization(&state, 13.6, electron_density).is_ok());
+        assert!(tracker.validate_saha_ionization(&state, 13.6, 0.0).is_err());
+    }
+    
+    #[test]
+    fn test_negative_temperature_opt_in() {
+        // A spin system past population inversion, relaxing at fixed negative T
+        let inverted = |entropy: f64, enthalpy: f64| {
+            let mut state = gas_state(-50.0, 101325.0, 1e-3, entropy, enthalpy);
+            state.population_inverted = true;
+            state
+        };
+        let initial_state = inverted(10.0, 100.0);
+        let final_state = inverted(10.5, 100.0);
+        
+        let result = ThermoStateTracker::new().validate_state_change(&initial_state, &final_state);
+        assert!(matches!(result, Err(ThermoValidationError::TemperatureBelowAbsoluteZero { .. })));
+        
+        let tracker = ThermoStateTracker::new().with_negative_temperature(true);
+        assert!(tracker.validate_state_change(&initial_state, &final_state).is_ok());
+        
+        // ΔG = ΔH − TΔS ≥ 0 at negative T; releasing enthalpy here lowers G
+        let result = tracker.validate_state_change(&initial_state, &inverted(10.5, 50.0));
+        assert!(matches!(
+            result,
+            Err(ThermoValidationError::InvertedGibbsFreeEnergyViolation { .. }),
+        ));
+        
+        // Only states flagged as population-inverted may go negative
+        let mut unflagged = final_state.clone();
+        unflagged.population_inverted = false;
+        assert!(tracker.validate_state_change(&initial_state, &unflagged).is_err());
+    }
+    
+    #[test]
+    fn test_isotherm_interpolation_keeps_pv_constant() {
+        let tracker = ThermoStateTracker::new();
+        let state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        
+        let states = tracker.interpolate_isotherm(&state, 2e5, 5e4, 6).unwrap();
+        assert_eq!(states.len(), 7);
+        assert_eq!(states[0].pressure, 2e5);
+        assert_eq!(states[6].pressure, 5e4);
+        
+        let pv = state.pressure * state.volume;
+        for intermediate in &states {
+            assert_eq!(intermediate.temperature, 300.0);
+            assert!((intermediate.pressure * intermediate.volume - pv).abs() / pv < 1e-12);
+        }
+        
+        assert!(tracker.interpolate_isotherm(&state, 2e5, 5e4, 0).is_err());
+        assert!(tracker.interpolate_isotherm(&state, 0.0, 5e4, 4).is_err());
+    }
+    
+    #[test]
+    fn test_water_phase_stability() {
+        let tracker = ThermoStateTracker::new();
+        let water = crate::utils::builtin_substances().remove("H2O").unwrap();
+        let water_state = |temperature: f64, phase: PhaseState| {
+            let mut state = gas_state(temperature, 101325.0, 1.8e-5, 50.0, 0.0);
+            state.substance_id = "H2O".to_string();
+            state.phase = phase;
+            state
+        };
+        
+        assert!(tracker.is_phase_stable(&water_state(250.0, PhaseState::Solid), &water));
+        assert!(tracker.is_phase_stable(&water_state(300.0, PhaseState::Liquid), &water));
+        assert!(tracker.is_phase_stable(&water_state(400.0, PhaseState::Gas), &water));
+        assert!(tracker.is_phase_stable(&water_state(700.0, PhaseState::Gas), &water));
+        
+        let result = tracker.validate_phase_stability(&water_state(250.0, PhaseState::Gas), &water);
+        match result {
+            Err(ThermoValidationError::PhaseNotStableAtConditions { declared, stable }) => {
+                assert_eq!(declared, PhaseState::Gas);
+                assert_eq!(stable, PhaseState::Solid);
+            }
+            other => panic!("expected phase stability error, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_pv_work_isobaric_and_isothermal() {
+        let tracker = ThermoStateTracker::new();
+        
+        // Isobaric expansion at 1 atm from 1 L to 3 L
+        let initial = gas_state(300.0, 101325.0, 1e-3, 191.6, 8700.0);
+        let final_state = gas_state(900.0, 101325.0, 3e-3, 220.0, 26100.0);
+        let work = tracker.pv_work(&initial, &final_state, true).unwrap();
+        assert!((work - 202.65).abs() < 1e-9);
+        
+        // Isothermal doubling of one mole at 300 K: W = RT·ln 2
+        let rt = PhysicalConstants::GAS_CONSTANT * 300.0;
+        let initial = gas_state(300.0, rt / 0.02, 0.02, 191.6, 8700.0);
+        let final_state = gas_state(300.0, rt / 0.04, 0.04, 197.4, 8700.0);
+        let work = tracker.pv_work(&initial, &final_state, false).unwrap();
+        assert!((work - rt * std::f64::consts::LN_2).abs() < 1e-9);
+        
+        // Compression does negative work
+        assert!(tracker.pv_work(&final_state, &initial, false).unwrap() < 0.0);
+        
+        let collapsed = gas_state(300.0, 101325.0, 0.0, 191.6, 8700.0);
+        assert!(matches!(
+            tracker.pv_work(&initial, &collapsed, true),
+            Err(ThermoValidationError::InvalidVolume { .. }),
+        ));
+    }
+    
+    #[test]
+    fn test_fixed_clock_timestamps_results() {
+        let tracker = ThermoStateTracker::new().with_clock(FixedClock(1700000000));
+        let initial = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        let final_state = gas_state(350.0, 101325.0, 0.0287, 196.1, 10150.0);
+        
+        let result = tracker.validate_state_change(&initial, &final_state).unwrap();
+        assert_eq!(result.timestamp, 1700000000);
+    }
+    
+    #[test]
+    fn test_temperature_penalty_weight_is_configurable() {
+        // Heating from 300 K to 1000 K exceeds the default 2× temperature change threshold
+        let initial = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        let final_state = gas_state(1000.0, 101325.0, 0.082, 226.0, 29000.0);
+        
+        let default_score = ThermoStateTracker::new()
+            .validate_state_change(&initial, &final_state)
+            .unwrap()
+            .confidence_score;
+        assert!((default_score - 0.8).abs() < 1e-9);
+        
+        let harsher = ConfidenceWeights { temperature_penalty: 0.5, ..ConfidenceWeights::default() };
+        let harsh_score = ThermoStateTracker::new()
+            .with_confidence_weights(harsher)
+            .validate_state_change(&initial, &final_state)
+            .unwrap()
+            .confidence_score;
+        assert!(harsh_score < default_score);
+        assert!((harsh_score - 0.5).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn test_refrigeration_step_satisfies_total_entropy() {
+        let tracker = ThermoStateTracker::new();
+        
+        // Isobaric cooling by 20 K: the gas loses 2 J/K and rejects 590 J of heat
+        let initial_state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        let cooled = gas_state(280.0, 101325.0, 0.02296, 189.6, 8110.0);
+        
+        // Treated as isolated, the entropy decrease is a violation
+        assert!(matches!(
+            tracker.validate_state_change(&initial_state, &cooled),
+            Err(ThermoValidationError::EntropyDecrease { .. }),
+        ));
+        
+        // The heat raises a 270 K reservoir's entropy by more than the gas loses
+        let cold_sink = HeatExchange { heat: -590.0, reservoir_temperature: 270.0 };
+        assert!(tracker
+            .validate_state_change_with_heat_exchange(&initial_state, &cooled, &cold_sink)
+            .is_ok());
+        
+        // A 310 K reservoir gains less than the gas loses
+        let warm_sink = HeatExchange { heat: -590.0, reservoir_temperature: 310.0 };
+        assert!(matches!(
+            tracker.validate_state_change_with_heat_exchange(&initial_state, &cooled, &warm_sink),
+            Err(ThermoValidationError::NegativeEntropyProduction { .. }),
+        ));
+    }
+    
+    #[test]
+    fn test_gibbs_field_consistency() {
+        let tracker = ThermoStateTracker::new();
+        let mut state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        
+        assert!((tracker.gibbs_energy(&state) - (8700.0 - 300.0 * 191.6)).abs() < 1e-9);
+        assert!(tracker.validate_gibbs_consistency(&state).is_ok());
+        
+        // Entropy updated without recomputing G
+        state.entropy = 195.0;
+        assert!(matches!(
+            tracker.validate_gibbs_consistency(&state),
+            Err(ThermoValidationError::GibbsFieldInconsistent { .. }),
+        ));
+    }
+    
+    #[test]
+    fn test_plasma_temperature_threshold() {
+        let tracker = ThermoStateTracker::new();
+        let mut state = gas_state(300.0, 1e3, 1.0, 200.0, 2e5);
+        state.phase = PhaseState::Plasma;
+        
+        assert!(matches!(
+            tracker.validate_physical_constraints(&state),
+            Err(ThermoValidationError::PlasmaTemperatureTooLow { .. }),
+        ));
+        
+        state.temperature = 10000.0;
+        assert!(tracker.validate_physical_constraints(&state).is_ok());
+        
+        let stricter = ThermoStateTracker::new().with_plasma_temperature_threshold(20000.0);
+        assert!(stricter.validate_physical_constraints(&state).is_err());
+    }
+    
+    #[test]
+    fn test_supercritical_state_requires_critical_point() {
+        let tracker = ThermoStateTracker::new();
+        let mut state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        state.phase = PhaseState::SuperCritical;
+        
+        // Nitrogen at room temperature but only 1 atm
+        assert!(matches!(
+            tracker.validate_physical_constraints(&state),
+            Err(ThermoValidationError::BelowCriticalPoint { .. }),
+        ));
+        
+        state.pressure = 5e6;
+        assert!(tracker.validate_physical_constraints(&state).is_ok());
+        
+        // Without a substance entry there is no critical point to compare against
+        state.substance_id = "unobtainium".to_string();
+        state.pressure = 101325.0;
+        assert!(tracker.validate_physical_constraints(&state).is_ok());
+    }
+    
+    #[test]
+    fn test_speed_of_sound_in_air() {
+        let tracker = ThermoStateTracker::new();
+        let air = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        
+        let speed = tracker.speed_of_sound(&air, 1.4, 0.028964).unwrap();
+        assert!((speed - 347.2).abs() < 0.1);
+        
+        assert!(matches!(
+            tracker.speed_of_sound(&air, 1.0, 0.028964),
+            Err(ThermoValidationError::InvalidHeatCapacityRatio { .. }),
+        ));
+        assert!(matches!(
+            tracker.speed_of_sound(&air, 1.4, 0.0),
+            Err(ThermoValidationError::InvalidMolarMass { .. }),
+        ));
+    }
+    
+    #[test]
+    fn test_equilibrium_uncertainty_grows_with_input_uncertainty() {
+        let tracker = ThermoStateTracker::new();
+        let state = gas_state(300.0, 101325.0, 0.0246, 191.6, 8700.0);
+        
+        let (nominal, exact) =
+            tracker.calculate_equilibrium_properties_with_uncertainty(&state, 0.0, 0.0);
+        assert_eq!(nominal.enthalpy, tracker.calculate_equilibrium_properties(&state).enthalpy);
+        assert_eq!(exact.enthalpy, 0.0);
+        assert_eq!(exact.gibbs_energy, 0.0);
+        
+        let sigmas: Vec<ThermodynamicState> = [(0.1, 10.0), (0.5, 50.0), (2.0, 200.0)]
+            .iter()
+            .map(|&(temp_sigma, pressure_sigma)| {
+                tracker
+                    .calculate_equilibrium_properties_with_uncertainty(&state, temp_sigma, pressure_sigma)
+                    .1
+            })
+            .collect();
+        
+        for pair in sigmas.windows(2) {
+            assert!(pair[1].internal_energy > pair[0].internal_energy);
+            assert!(pair[1].enthalpy > pair[0].enthalpy);
+            assert!(pair[1].entropy > pair[0].entropy);
+            assert!(pair[1].gibbs_energy > pair[0].gibbs_energy);
+        }
+    }
+    
+    #[test]
+    fn test_transition_temperature_matches_substance() {
+        let tracker = ThermoStateTracker::new();
+        let water = crate::utils::builtin_substances().remove("H2O").unwrap();
+        
+        let mut ice = gas_state(273.15, 101325.0, 1.963e-5, 41.0, -6010.0);
+        ice.phase = PhaseState::Solid;
+        let mut melt = gas_state(273.15, 101325.0, 1.8e-5, 63.0, 0.0);
+        melt.phase = PhaseState::Liquid;
+        assert!(tracker.validate_transition_temperature(&ice, &melt, &water).is_ok());
+        
+        // Boiling at 1 atm, within the accuracy of a constant-ΔH Clausius–Clapeyron curve
+        let mut steam = gas_state(373.15, 101325.0, 0.0306, 196.0, 40660.0);
+        steam.phase = PhaseState::Gas;
+        let mut boiling = melt.clone();
+        boiling.temperature = 373.15;
+        assert!(tracker.validate_transition_temperature(&boiling, &steam, &water).is_ok());
+        
+        ice.temperature = 350.0;
+        melt.temperature = 350.0;
+        match tracker.validate_transition_temperature(&ice, &melt, &water) {
+            Err(ThermoValidationError::TransitionTemperatureMismatch { expected, actual }) => {
+                assert_eq!(expected, 273.16);
+                assert_eq!(actual, 350.0);
+            }
+            other => panic!("expected transition temperature mismatch, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_transition_temperature_outside_coexistence() {
+        let tracker = ThermoStateTracker::new();
+        let water = crate::utils::builtin_substances().remove("H2O").unwrap();
+        
+        // Above the 22.1 MPa critical pressure liquid and vapour are indistinguishable
+        let mut liquid = gas_state(700.0, 3e7, 1.8e-5, 63.0, 0.0);
+        liquid.phase = PhaseState::Liquid;
+        let steam = gas_state(700.0, 3e7, 1e-4, 150.0, 40660.0);
+        assert!(matches!(
+            tracker.validate_transition_temperature(&liquid, &steam, &water),
+            Err(ThermoValidationError::NoPhaseCoexistence { pressure }) if pressure == 3e7
+        ));
+        
+        // The sublimation curve diverges near 5e11 Pa instead of yielding a negative temperature
+        let mut ice = gas_state(300.0, 1e12, 1.963e-5, 41.0, -6010.0);
+        ice.phase = PhaseState::Solid;
+        let vapour = gas_state(300.0, 1e12, 1e-5, 190.0, 46670.0);
+        match tracker.validate_transition_temperature(&ice, &vapour, &water) {
+            Err(error @ ThermoValidationError::NoPhaseCoexistence { .. }) => {
+                assert_eq!(error.failed_check(), FailedCheck::PhaseTransition);
+            }
+            other => panic!("expected no phase coexistence, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_internal_energy_equipartition() {
+        let tracker = ThermoStateTracker::new();
+        let rt = PhysicalConstants::GAS_CONSTANT * 300.0;
+        let state = gas_state(300.0, 101325.0, rt / 101325.0, 191.6, 8723.0);
+        
+        let monatomic = tracker.calculate_internal_energy(&state, 3);
+        let diatomic = tracker.calculate_internal_energy(&state, 5);
+        let vibrating = tracker.calculate_internal_energy(&state, 7);
+        assert!((monatomic - 1.5 * rt).abs() < 1e-9);
+        assert!((diatomic - 2.5 * rt).abs() < 1e-9);
+        assert!((vibrating - 3.5 * rt).abs() < 1e-9);
+        assert!((vibrating - diatomic - (diatomic - monatomic)).abs() < 1e-9);
+        
+        // Doubling the volume at the same T and P doubles the amount of substance
+        let two_moles = gas_state(300.0, 101325.0, 2.0 * rt / 101325.0, 191.6, 8723.0);
+        assert!((tracker.calculate_internal_energy(&two_moles, 3) - 3.0 * rt).abs() < 1e-9);
+        
+        // The monatomic default is unchanged, and the molecule type feeds U and H
+        assert_eq!(tracker.calculate_equilibrium_properties(&state).internal_energy, monatomic);
+        let n2 = tracker.calculate_equilibrium_properties_for(&state, MoleculeType::Diatomic);
+        assert_eq!(n2.internal_energy, diatomic);
+        assert_eq!(n2.enthalpy, diatomic + state.pressure * state.volume);
     }
 }
\ No newline at end of file
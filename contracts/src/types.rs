@@ -1,19 +1,368 @@
 //! Common types used across smart contracts
 
+use crate::utils::{self, UtilsError};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use thiserror::Error;
 
 /// Represents a quantum energy transition
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct QuantumTransition {
+    #[serde(alias = "electronId")]
     pub electron_id: String,
+    #[serde(alias = "initialEnergy")]
     pub initial_energy: f64,  // eV
+    #[serde(alias = "finalEnergy")]
     pub final_energy: f64,    // eV
+    #[serde(alias = "photonEnergy")]
     pub photon_energy: f64,   // eV
     pub wavelength: f64,      // nm
     pub frequency: f64,       // Hz
     pub timestamp: u64,
     pub validated: bool,
+    #[serde(alias = "validatorId")]
     pub validator_id: String,
+    #[serde(default)]
+    pub relativistic: bool,   // photon_energy includes fine-structure corrections
+    #[serde(default, alias = "declaredKind")]
+    pub declared_kind: Option<TransitionKind>,
+    #[serde(default, alias = "sourceVelocityMs")]
+    pub source_velocity_ms: Option<f64>, // line-of-sight velocity, positive when receding
+    #[serde(default, alias = "energyUncertainty")]
+    pub energy_uncertainty: Option<f64>,    // eV, 1σ measurement uncertainty of photon_energy
+    #[serde(default, alias = "frequencyUncertainty")]
+    pub frequency_uncertainty: Option<f64>, // Hz, 1σ measurement uncertainty of frequency
+    #[serde(default, alias = "nuclearMassKg")]
+    pub nuclear_mass_kg: Option<f64>,       // kg, finite nuclear mass for reduced-mass corrections
+    #[serde(default, alias = "orbitalAngularMomentum")]
+    pub orbital_angular_momentum: Option<(u32, u32)>, // (l_initial, l_final), dipole rule Δl = ±1
+}
+
+/// Direction of a quantum transition
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TransitionKind {
+    Emission,
+    Absorption,
+}
+
+impl QuantumTransition {
+    /// Stores a wavelength given in any unit, normalized to nanometres
+    pub fn set_wavelength(&mut self, wavelength: f64, unit: WavelengthUnit) {
+        self.wavelength = unit.to_nm(wavelength);
+    }
+    
+    /// Returns the wavelength converted to `unit`
+    pub fn wavelength_in(&self, unit: WavelengthUnit) -> f64 {
+        unit.from_nm(self.wavelength)
+    }
+    
+    /// Derives the transition direction from the level energies
+    ///
+    /// A lower final energy is emission and a higher one is absorption; equal
+    /// energies describe no transition and yield `None`.
+    pub fn kind(&self) -> Option<TransitionKind> {
+        if self.final_energy < self.initial_energy {
+            Some(TransitionKind::Emission)
+        } else if self.final_energy > self.initial_energy {
+            Some(TransitionKind::Absorption)
+        } else {
+            None
+        }
+    }
+}
+
+// Equality and hashing compare floating-point fields by bit pattern so that
+// transitions can key a HashMap: identical payloads (including NaN fields) are
+// equal, while 0.0 and -0.0 are treated as distinct
+impl PartialEq for QuantumTransition {
+    fn eq(&self, other: &Self) -> bool {
+        self.electron_id == other.electron_id
+            && self.initial_energy.to_bits() == other.initial_energy.to_bits()
+            && self.final_energy.to_bits() == other.final_energy.to_bits()
+            && self.photon_energy.to_bits() == other.photon_energy.to_bits()
+            && self.wavelength.to_bits() == other.wavelength.to_bits()
+            && self.frequency.to_bits() == other.frequency.to_bits()
+            && self.timestamp == other.timestamp
+            && self.validated == other.validated
+            && self.validator_id == other.validator_id
+            && self.relativistic == other.relativistic
+            && self.declared_kind == other.declared_kind
+            && self.source_velocity_ms.map(f64::to_bits) == other.source_velocity_ms.map(f64::to_bits)
+            && self.energy_uncertainty.map(f64::to_bits) == other.energy_uncertainty.map(f64::to_bits)
+            && self.frequency_uncertainty.map(f64::to_bits)
+                == other.frequency_uncertainty.map(f64::to_bits)
+            && self.nuclear_mass_kg.map(f64::to_bits) == other.nuclear_mass_kg.map(f64::to_bits)
+            && self.orbital_angular_momentum == other.orbital_angular_momentum
+    }
+}
+
+impl Eq for QuantumTransition {}
+
+impl Hash for QuantumTransition {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.electron_id.hash(state);
+        self.initial_energy.to_bits().hash(state);
+        self.final_energy.to_bits().hash(state);
+        self.photon_energy.to_bits().hash(state);
+        self.wavelength.to_bits().hash(state);
+        self.frequency.to_bits().hash(state);
+        self.timestamp.hash(state);
+        self.validated.hash(state);
+        self.validator_id.hash(state);
+        self.relativistic.hash(state);
+        self.declared_kind.hash(state);
+        self.source_velocity_ms.map(f64::to_bits).hash(state);
+        self.energy_uncertainty.map(f64::to_bits).hash(state);
+        self.frequency_uncertainty.map(f64::to_bits).hash(state);
+        self.nuclear_mass_kg.map(f64::to_bits).hash(state);
+        self.orbital_angular_momentum.hash(state);
+    }
+}
+
+/// Errors decoding the binary encoding of a `QuantumTransition`
+#[derive(Error, Debug, PartialEq)]
+pub enum DecodeError {
+    #[error("Unexpected end of input: needed {needed} bytes, {remaining} remaining")]
+    UnexpectedEnd { needed: usize, remaining: usize },
+    #[error("Invalid UTF-8 in field {field}")]
+    InvalidUtf8 { field: &'static str },
+    #[error("Invalid tag {tag} in field {field}")]
+    InvalidTag { field: &'static str, tag: u8 },
+    #[error("{count} trailing bytes after transition")]
+    TrailingBytes { count: usize },
+}
+
+impl QuantumTransition {
+    /// Encodes the transition in a deterministic, platform-independent binary layout
+    ///
+    /// Fields are written in declaration order with no padding:
+    ///
+    /// | field                      | encoding                                         |
+    /// |----------------------------|--------------------------------------------------|
+    /// | `electron_id`              | u64 LE byte length, then UTF-8 bytes             |
+    /// | `initial_energy`           | f64 LE (IEEE 754 bit pattern)                    |
+    /// | `final_energy`             | f64 LE                                           |
+    /// | `photon_energy`            | f64 LE                                           |
+    /// | `wavelength`               | f64 LE                                           |
+    /// | `frequency`                | f64 LE                                           |
+    /// | `timestamp`                | u64 LE                                           |
+    /// | `validated`                | u8, 0 or 1                                       |
+    /// | `validator_id`             | u64 LE byte length, then UTF-8 bytes             |
+    /// | `relativistic`             | u8, 0 or 1                                       |
+    /// | `declared_kind`            | u8, 0 = none, 1 = emission, 2 = absorption       |
+    /// | `source_velocity_ms`       | u8, 0 = none, 1 = present followed by f64 LE     |
+    /// | `energy_uncertainty`       | u8, 0 = none, 1 = present followed by f64 LE     |
+    /// | `frequency_uncertainty`    | u8, 0 = none, 1 = present followed by f64 LE     |
+    /// | `nuclear_mass_kg`          | u8, 0 = none, 1 = present followed by f64 LE     |
+    /// | `orbital_angular_momentum` | u8, 0 = none, 1 = present followed by two u32 LE |
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(
+            112 + self.electron_id.len() + self.validator_id.len(),
+        );
+        
+        let put_str = |bytes: &mut Vec<u8>, value: &str| {
+            bytes.extend_from_slice(&(value.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(value.as_bytes());
+        };
+        
+        put_str(&mut bytes, &self.electron_id);
+        for value in [
+            self.initial_energy,
+            self.final_energy,
+            self.photon_energy,
+            self.wavelength,
+            self.frequency,
+        ] {
+            bytes.extend_from_slice(&value.to_bits().to_le_bytes());
+        }
+        bytes.extend_from_slice(&self.timestamp.to_le_bytes());
+        bytes.push(self.validated as u8);
+        put_str(&mut bytes, &self.validator_id);
+        bytes.push(self.relativistic as u8);
+        bytes.push(match self.declared_kind {
+            None => 0,
+            Some(TransitionKind::Emission) => 1,
+            Some(TransitionKind::Absorption) => 2,
+        });
+        for value in [
+            self.source_velocity_ms,
+            self.energy_uncertainty,
+            self.frequency_uncertainty,
+            self.nuclear_mass_kg,
+        ] {
+            match value {
+                None => bytes.push(0),
+                Some(value) => {
+                    bytes.push(1);
+                    bytes.extend_from_slice(&value.to_bits().to_le_bytes());
+                }
+            }
+        }
+        match self.orbital_angular_momentum {
+            None => bytes.push(0),
+            Some((l_initial, l_final)) => {
+                bytes.push(1);
+                bytes.extend_from_slice(&l_initial.to_le_bytes());
+                bytes.extend_from_slice(&l_final.to_le_bytes());
+            }
+        }
+        
+        bytes
+    }
+    
+    /// Decodes a transition produced by `to_bytes`, rejecting trailing data
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = ByteReader { bytes };
+        
+        let transition = QuantumTransition {
+            electron_id: reader.string("electron_id")?,
+            initial_energy: reader.f64()?,
+            final_energy: reader.f64()?,
+            photon_energy: reader.f64()?,
+            wavelength: reader.f64()?,
+            frequency: reader.f64()?,
+            timestamp: reader.u64()?,
+            validated: reader.flag("validated")?,
+            validator_id: reader.string("validator_id")?,
+            relativistic: reader.flag("relativistic")?,
+            declared_kind: match reader.u8()? {
+                0 => None,
+                1 => Some(TransitionKind::Emission),
+                2 => Some(TransitionKind::Absorption),
+                tag => return Err(DecodeError::InvalidTag { field: "declared_kind", tag }),
+            },
+            source_velocity_ms: reader.optional_f64("source_velocity_ms")?,
+            energy_uncertainty: reader.optional_f64("energy_uncertainty")?,
+            frequency_uncertainty: reader.optional_f64("frequency_uncertainty")?,
+            nuclear_mass_kg: reader.optional_f64("nuclear_mass_kg")?,
+            orbital_angular_momentum: match reader.flag("orbital_angular_momentum")? {
+                false => None,
+                true => Some((reader.u32()?, reader.u32()?)),
+            },
+        };
+        
+        if !reader.bytes.is_empty() {
+            return Err(DecodeError::TrailingBytes { count: reader.bytes.len() });
+        }
+        
+        Ok(transition)
+    }
+}
+
+/// Cursor over the binary encoding of a `QuantumTransition`
+struct ByteReader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ByteReader<'a> {
+    fn take(&mut self, needed: usize) -> Result<&'a [u8], DecodeError> {
+        if self.bytes.len() < needed {
+            return Err(DecodeError::UnexpectedEnd {
+                needed,
+                remaining: self.bytes.len(),
+            });
+        }
+        
+        let (head, tail) = self.bytes.split_at(needed);
+        self.bytes = tail;
+        Ok(head)
+    }
+    
+    fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.take(1)?[0])
+    }
+    
+    fn u32(&mut self) -> Result<u32, DecodeError> {
+        let mut buf = [0u8; 4];
+        buf.copy_from_slice(self.take(4)?);
+        Ok(u32::from_le_bytes(buf))
+    }
+    
+    fn u64(&mut self) -> Result<u64, DecodeError> {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(self.take(8)?);
+        Ok(u64::from_le_bytes(buf))
+    }
+    
+    fn f64(&mut self) -> Result<f64, DecodeError> {
+        Ok(f64::from_bits(self.u64()?))
+    }
+    
+    fn optional_f64(&mut self, field: &'static str) -> Result<Option<f64>, DecodeError> {
+        match self.flag(field)? {
+            false => Ok(None),
+            true => Ok(Some(self.f64()?)),
+        }
+    }
+    
+    fn flag(&mut self, field: &'static str) -> Result<bool, DecodeError> {
+        match self.u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            tag => Err(DecodeError::InvalidTag { field, tag }),
+        }
+    }
+    
+    fn string(&mut self, field: &'static str) -> Result<String, DecodeError> {
+        let length = usize::try_from(self.u64()?).unwrap_or(usize::MAX);
+        let raw = self.take(length)?;
+        String::from_utf8(raw.to_vec()).map_err(|_| DecodeError::InvalidUtf8 { field })
+    }
+}
+
+/// Reference transition whose matches earn a confidence boost
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct KnownTransition {
+    #[serde(alias = "initialEnergy")]
+    pub initial_energy: f64, // eV
+    #[serde(alias = "finalEnergy")]
+    pub final_energy: f64,   // eV
+    pub tolerance: f64,      // eV, allowed deviation of each level
+    pub series: String,
+}
+
+/// Rovibrational transition of a diatomic molecule
+///
+/// Levels follow the harmonic-oscillator/rigid-rotor model
+/// E(v, J) = hν(v + ½) + B·J(J + 1).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct MolecularTransition {
+    #[serde(alias = "moleculeId")]
+    pub molecule_id: String,
+    #[serde(alias = "vInitial")]
+    pub v_initial: u32,
+    #[serde(alias = "jInitial")]
+    pub j_initial: u32,
+    #[serde(alias = "vFinal")]
+    pub v_final: u32,
+    #[serde(alias = "jFinal")]
+    pub j_final: u32,
+    #[serde(alias = "harmonicFrequency")]
+    pub harmonic_frequency: f64,  // Hz, vibrational frequency ν = ω/2π
+    #[serde(alias = "rotationalConstant")]
+    pub rotational_constant: f64, // eV
+    #[serde(alias = "photonEnergy")]
+    pub photon_energy: f64,       // eV
+    #[serde(default, alias = "energyUncertainty")]
+    pub energy_uncertainty: Option<f64>, // eV, 1σ measurement uncertainty of photon_energy
+    pub timestamp: u64,
+}
+
+/// Spectral bands of electromagnetic radiation, ordered by decreasing wavelength
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum SpectralBand {
+    Radio,
+    Microwave,
+    Infrared,
+    Visible,
+    Ultraviolet,
+    XRay,
+    Gamma,
 }
 
 /// Thermodynamic phase states
@@ -27,9 +376,136 @@ pub enum PhaseState {
     BoseEinsteinCondensate,
 }
 
+/// Molecular geometries, by the quadratic degrees of freedom active at ordinary temperatures
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum MoleculeType {
+    Monatomic,
+    Diatomic,
+    DiatomicVibrating,
+    Polyatomic,
+}
+
+impl MoleculeType {
+    /// Degrees of freedom counted by equipartition
+    ///
+    /// Translation gives 3, rotation adds 2 for linear and 3 for nonlinear
+    /// molecules, and an active vibrational mode adds 2 (kinetic and potential).
+    pub fn degrees_of_freedom(self) -> u32 {
+        match self {
+            MoleculeType::Monatomic => 3,
+            MoleculeType::Diatomic => 5,
+            MoleculeType::DiatomicVibrating => 7,
+            MoleculeType::Polyatomic => 6,
+        }
+    }
+}
+
+/// Length units wavelengths may be given in, normalized to nanometres before storage
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum WavelengthUnit {
+    Nanometer,
+    Angstrom,
+    Micrometer,
+}
+
+impl WavelengthUnit {
+    /// Number of nanometres in one of this unit
+    fn nanometers(self) -> f64 {
+        match self {
+            WavelengthUnit::Nanometer => 1.0,
+            WavelengthUnit::Angstrom => 0.1,
+            WavelengthUnit::Micrometer => 1e3,
+        }
+    }
+    
+    /// Converts a wavelength in this unit to nanometres
+    pub fn to_nm(self, wavelength: f64) -> f64 {
+        wavelength * self.nanometers()
+    }
+    
+    /// Converts a wavelength in nanometres to this unit
+    pub fn from_nm(self, wavelength_nm: f64) -> f64 {
+        wavelength_nm / self.nanometers()
+    }
+}
+
+/// Temperature in a choice of units, normalized to Kelvin before storage
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Temperature {
+    Kelvin(f64),
+    Celsius(f64),
+    Fahrenheit(f64),
+}
+
+impl Temperature {
+    /// Converts to Kelvin, rejecting values below absolute zero
+    pub fn kelvin(self) -> Result<f64, UtilsError> {
+        match self {
+            Temperature::Kelvin(kelvin) => utils::kelvin(kelvin),
+            Temperature::Celsius(celsius) => utils::celsius_to_kelvin(celsius),
+            Temperature::Fahrenheit(fahrenheit) => utils::fahrenheit_to_kelvin(fahrenheit),
+        }
+    }
+}
+
+/// Pressure in a choice of units, normalized to Pascal before storage
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Pressure {
+    Pascal(f64),
+    Bar(f64),
+    Atmosphere(f64),
+}
+
+impl Pressure {
+    /// Converts to Pascal
+    pub fn pascal(self) -> f64 {
+        match self {
+            Pressure::Pascal(pascal) => pascal,
+            Pressure::Bar(bar) => utils::bar_to_pascal(bar),
+            Pressure::Atmosphere(atm) => utils::atm_to_pascal(atm),
+        }
+    }
+}
+
+/// Process constraints that a state change must satisfy
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum ProcessConstraint {
+    Isothermal,
+    Isentropic,
+    Isobaric,
+    Isochoric,
+    Adiabatic,
+}
+
+/// Heat absorbed by a system from a reservoir during a state change
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct HeatExchange {
+    pub heat: f64,                  // J, positive when absorbed by the system
+    #[serde(alias = "reservoirTemperature")]
+    pub reservoir_temperature: f64, // Kelvin
+}
+
+/// Thermodynamic character of a process based on the sign of ΔG
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum Spontaneity {
+    Spontaneous,
+    Equilibrium,
+    NonSpontaneous,
+}
+
+/// How a tolerance is applied: as an absolute bound or scaled by the reference value
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum ToleranceMode {
+    Absolute,
+    Relative,
+}
+
 /// Represents a thermodynamic state
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct ThermodynamicState {
+    #[serde(alias = "substanceId")]
     pub substance_id: String,
     pub temperature: f64,     // Kelvin
     pub pressure: f64,        // Pascal
@@ -37,22 +513,54 @@ pub struct ThermodynamicState {
     pub phase: PhaseState,
     pub entropy: f64,         // J/K
     pub enthalpy: f64,        // J
+    #[serde(alias = "gibbsEnergy")]
     pub gibbs_energy: f64,    // J
+    #[serde(alias = "internalEnergy")]
     pub internal_energy: f64, // J
     pub timestamp: u64,
     pub validated: bool,
+    #[serde(default, alias = "populationInverted")]
+    pub population_inverted: bool, // may carry a negative absolute temperature
+}
+
+/// Per-substance physical properties
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct SubstanceProperties {
+    pub name: String,
+    #[serde(alias = "molarMass")]
+    pub molar_mass: f64,                        // kg/mol
+    #[serde(alias = "criticalTemperature")]
+    pub critical_temperature: f64,              // Kelvin
+    #[serde(alias = "criticalPressure")]
+    pub critical_pressure: f64,                 // Pascal
+    #[serde(alias = "triplePointTemperature")]
+    pub triple_point_temperature: Option<f64>,  // Kelvin
+    #[serde(alias = "triplePointPressure")]
+    pub triple_point_pressure: Option<f64>,     // Pascal
+    #[serde(alias = "enthalpyOfFusion")]
+    pub enthalpy_of_fusion: Option<f64>,        // J/mol
+    #[serde(alias = "enthalpyOfVaporization")]
+    pub enthalpy_of_vaporization: f64,          // J/mol
 }
 
 /// Energy ledger entry
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct EnergyLedgerEntry {
     pub id: String,
+    #[serde(alias = "entryType")]
     pub entry_type: EntryType,
+    #[serde(alias = "energyChange")]
     pub energy_change: f64,   // J
+    #[serde(alias = "initialState")]
     pub initial_state: String,
+    #[serde(alias = "finalState")]
     pub final_state: String,
+    #[serde(alias = "validationHash")]
     pub validation_hash: String,
     pub timestamp: u64,
+    #[serde(alias = "blockHeight")]
     pub block_height: u64,
 }
 
@@ -67,12 +575,411 @@ pub enum EntryType {
 
 /// Validation result
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
 pub struct ValidationResult {
+    #[serde(alias = "isValid")]
     pub is_valid: bool,
+    #[serde(alias = "errorMessage")]
     pub error_message: Option<String>,
+    #[serde(alias = "confidenceScore")]
     pub confidence_score: f64,
+    #[serde(alias = "validatorConsensus")]
     pub validator_consensus: Vec<String>,
     pub timestamp: u64,
+    #[serde(default, alias = "failedCheck")]
+    pub failed_check: Option<FailedCheck>,
+    #[serde(default)]
+    pub notes: Vec<String>, // caveats on a valid result, e.g. "forbidden_transition"
+}
+
+/// How `ValidationResult::combine` merges two confidence scores
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum CombineMode {
+    Conservative, // minimum of the two scores
+    Independent,  // product of the two scores
+}
+
+impl ValidationResult {
+    /// Merges the results of two validators that checked the same input
+    ///
+    /// The combination is valid only if both are, error messages are joined,
+    /// the consensus lists and notes are merged without duplicates, and the
+    /// later timestamp is kept.
+    pub fn combine(&self, other: &ValidationResult, mode: CombineMode) -> ValidationResult {
+        let confidence_score = match mode {
+            CombineMode::Conservative => self.confidence_score.min(other.confidence_score),
+            CombineMode::Independent => self.confidence_score * other.confidence_score,
+        };
+        
+        let messages: Vec<&str> = [&self.error_message, &other.error_message]
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+            .collect();
+        
+        let mut validator_consensus = self.validator_consensus.clone();
+        for validator in &other.validator_consensus {
+            if !validator_consensus.contains(validator) {
+                validator_consensus.push(validator.clone());
+            }
+        }
+        
+        let mut notes = self.notes.clone();
+        for note in &other.notes {
+            if !notes.contains(note) {
+                notes.push(note.clone());
+            }
+        }
+        
+        ValidationResult {
+            is_valid: self.is_valid && other.is_valid,
+            error_message: if messages.is_empty() { None } else { Some(messages.join("; ")) },
+            confidence_score,
+            validator_consensus,
+            timestamp: self.timestamp.max(other.timestamp),
+            failed_check: self.failed_check.clone().or_else(|| other.failed_check.clone()),
+            notes,
+        }
+    }
+    
+    /// Deterministic SHA-256 hex digest of the validation outcome, excluding the timestamp
+    ///
+    /// Covers `is_valid`, the confidence score rounded to six decimal places, and
+    /// the validator consensus in sorted order, so reordering validators does not
+    /// change the fingerprint.
+    pub fn fingerprint(&self) -> String {
+        self.digest(false)
+    }
+    
+    /// Same as `fingerprint`, additionally covering the timestamp
+    pub fn fingerprint_with_timestamp(&self) -> String {
+        self.digest(true)
+    }
+    
+    fn digest(&self, include_timestamp: bool) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update([self.is_valid as u8]);
+        hasher.update(format!("{:.6}", self.confidence_score).as_bytes());
+        
+        let mut consensus: Vec<&String> = self.validator_consensus.iter().collect();
+        consensus.sort();
+        for validator in consensus {
+            // Length prefixes keep adjacent names from running together
+            hasher.update((validator.len() as u64).to_le_bytes());
+            hasher.update(validator.as_bytes());
+        }
+        
+        if include_timestamp {
+            hasher.update(self.timestamp.to_le_bytes());
+        }
+        
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+/// Tolerances applied by the validators' checks
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct Tolerances {
+    pub energy: f64,      // eV, energy conservation
+    pub splitting: f64,   // eV, fine-structure doublets
+    pub frequency: f64,   // relative, E = hf
+    pub wavelength: f64,  // relative, c = λf
+    pub entropy: f64,     // J/K, or relative under ToleranceMode::Relative
+    pub gibbs: f64,       // J
+    pub process: f64,     // relative, process constraints
+    #[serde(alias = "idealGas")]
+    pub ideal_gas: f64,   // relative, PV = nRT
+    #[serde(alias = "latentHeat")]
+    pub latent_heat: f64, // relative
+    #[serde(alias = "transitionTemperature")]
+    pub transition_temperature: f64, // relative, phase transition temperature
+    pub transfer: f64,    // J, energy transfer balance
+    pub coverage: f64,    // σ, acceptance band when measurement uncertainties are given
+}
+
+impl Default for Tolerances {
+    fn default() -> Self {
+        Tolerances {
+            energy: 1e-15,
+            splitting: 1e-5,
+            frequency: 1e-10,
+            wavelength: 1e-10,
+            entropy: 1e-6,
+            gibbs: 1e-3,
+            process: 1e-6,
+            ideal_gas: 1e-3,
+            latent_heat: 0.05,
+            transition_temperature: 0.05,
+            transfer: 1e-6,
+            coverage: 2.0,
+        }
+    }
+}
+
+/// Factors and thresholds shaping the thermodynamic confidence score
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct ConfidenceWeights {
+    #[serde(alias = "irreversibilityPenalty")]
+    pub irreversibility_penalty: f64,   // fraction lost for a fully irreversible process
+    #[serde(alias = "temperatureChangeRatio")]
+    pub temperature_change_ratio: f64,  // relative ΔT above which the penalty applies
+    #[serde(alias = "temperaturePenalty")]
+    pub temperature_penalty: f64,       // factor for extreme temperature changes
+    #[serde(alias = "pressureChangeRatio")]
+    pub pressure_change_ratio: f64,     // relative ΔP above which the penalty applies
+    #[serde(alias = "pressurePenalty")]
+    pub pressure_penalty: f64,          // factor for extreme pressure changes
+    #[serde(alias = "commonTransitionBoost")]
+    pub common_transition_boost: f64,   // factor for well-known phase transitions
+}
+
+impl Default for ConfidenceWeights {
+    fn default() -> Self {
+        ConfidenceWeights {
+            irreversibility_penalty: 0.5,
+            temperature_change_ratio: 2.0,
+            temperature_penalty: 0.8,
+            pressure_change_ratio: 10.0,
+            pressure_penalty: 0.9,
+            common_transition_boost: 1.1,
+        }
+    }
+}
+
+/// Number of decimal places confidence scores are rounded to before reporting
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct ConfidencePrecision {
+    #[serde(alias = "decimalPlaces")]
+    pub decimal_places: u32,
+}
+
+impl Default for ConfidencePrecision {
+    fn default() -> Self {
+        ConfidencePrecision { decimal_places: 6 }
+    }
+}
+
+impl ConfidencePrecision {
+    /// Rounds half away from zero, which is exact and platform-independent in IEEE 754
+    pub fn round(&self, value: f64) -> f64 {
+        let scale = 10f64.powi(self.decimal_places as i32);
+        (value * scale).round() / scale
+    }
+}
+
+/// Callback invoked with each failed validation result
+pub type FailureObserver = Box<dyn Fn(&ValidationResult) + Send + Sync>;
+
+/// Source of the timestamps recorded on validation results
+pub trait Clock: Send + Sync {
+    /// Seconds since the Unix epoch
+    fn now_secs(&self) -> u64;
+}
+
+/// Clock reading the system time
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_secs(&self) -> u64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+    }
+}
+
+/// Clock stuck at a fixed instant, for reproducible results across nodes and tests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(pub u64);
+
+impl Clock for FixedClock {
+    fn now_secs(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Aggregate statistics over a batch of validation results
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct ValidationSummary {
+    pub total: usize,
+    #[serde(alias = "validCount")]
+    pub valid_count: usize,
+    #[serde(alias = "invalidCount")]
+    pub invalid_count: usize,
+    #[serde(alias = "meanConfidence")]
+    pub mean_confidence: f64,
+    #[serde(alias = "failureReasons")]
+    pub failure_reasons: HashMap<String, usize>,
+}
+
+/// Computed value of a single validation check compared against its threshold
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CheckExplanation {
+    pub check: FailedCheck,
+    pub value: f64,
+    pub threshold: f64,
+    pub passed: bool,
+}
+
+/// One sub-check recorded, in execution order, by a traced validation
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TraceEvent {
+    pub check: FailedCheck,
+    pub inputs: Vec<(String, f64)>,
+    pub value: f64,
+    pub threshold: f64,
+    pub passed: bool,
+    #[serde(default)]
+    pub penalized: bool, // failed but accepted with a confidence penalty, counted as passed
+}
+
+/// Per-check breakdown of a validation, reported whether or not it passed
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ValidationExplanation {
+    pub checks: Vec<CheckExplanation>,
+}
+
+impl ValidationExplanation {
+    /// Returns true if every check passed
+    pub fn all_passed(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+}
+
+/// Validation rule that caused a failure
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum FailedCheck {
+    NonFiniteInput,
+    EnergyConservation,
+    SelectionRules,
+    InvalidEnergyLevel,
+    FrequencyMismatch,
+    WavelengthMismatch,
+    PhysicalConstraints,
+    ProcessConstraint,
+    EntropyDecrease,
+    PhaseTransition,
+    GibbsFreeEnergy,
+    CarnotBound,
+    IdealGasConsistency,
+    ProcessPath,
+    BecFeasibility,
+    FineStructure,
+    TransitionDirection,
+    EnergyTransfer,
+    LatentHeat,
+    TemporalOrdering,
+    Ionization,
+    PhaseStability,
+    LowConfidence,
+    ChargeConservation,
+    ComptonKinematics,
+    NuclearMass,
+}
+
+/// Error type of a validator, identifying the rule that failed
+pub trait ValidationFailure: std::error::Error {
+    fn failed_check(&self) -> FailedCheck;
+}
+
+/// Validator input that can be dumped for logging regardless of its concrete type
+pub trait ValidatableInput {
+    /// Serializes the input to a JSON document
+    fn to_debug_json(&self) -> String;
+}
+
+impl ValidatableInput for QuantumTransition {
+    fn to_debug_json(&self) -> String {
+        debug_json(self)
+    }
+}
+
+impl ValidatableInput for ThermodynamicState {
+    fn to_debug_json(&self) -> String {
+        debug_json(self)
+    }
+}
+
+/// Serializes `value` to JSON, falling back to a JSON-wrapped `Debug` rendering
+fn debug_json<T: Serialize + std::fmt::Debug>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_else(|error| {
+        serde_json::json!({ "error": error.to_string(), "debug": format!("{:?}", value) })
+            .to_string()
+    })
+}
+
+/// Common interface implemented by all validators
+pub trait Validator {
+    type Input;
+    type Error: ValidationFailure;
+    
+    /// Validates a single input
+    fn validate(&self, input: &Self::Input) -> Result<ValidationResult, Self::Error>;
+}
+
+/// Instantiable set of physical constants, allowing historical CODATA revisions
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "camel-case", serde(rename_all(serialize = "camelCase")))]
+pub struct ConstantsSet {
+    #[serde(alias = "codataVersion")]
+    pub codata_version: u16,
+    #[serde(alias = "planckConstant")]
+    pub planck_constant: f64,            // J⋅s
+    #[serde(alias = "speedOfLight")]
+    pub speed_of_light: f64,             // m/s
+    #[serde(alias = "boltzmannConstant")]
+    pub boltzmann_constant: f64,         // J/K
+    #[serde(alias = "gasConstant")]
+    pub gas_constant: f64,               // J/(mol⋅K)
+    #[serde(alias = "avogadroNumber")]
+    pub avogadro_number: f64,            // mol⁻¹
+    #[serde(alias = "electronCharge")]
+    pub electron_charge: f64,            // C
+    #[serde(alias = "electronMass")]
+    pub electron_mass: f64,              // kg
+    #[serde(alias = "protonMass")]
+    pub proton_mass: f64,                // kg
+    #[serde(alias = "fineStructureConstant")]
+    pub fine_structure_constant: f64,    // dimensionless
+    #[serde(alias = "wienDisplacementConstant")]
+    pub wien_displacement_constant: f64, // m⋅K
+    #[serde(alias = "stefanBoltzmannConstant")]
+    pub stefan_boltzmann_constant: f64,  // W/(m²⋅K⁴)
+}
+
+impl ConstantsSet {
+    /// CODATA 2018 recommended values, identical to `PhysicalConstants`
+    pub fn codata_2018() -> Self {
+        ConstantsSet {
+            codata_version: 2018,
+            planck_constant: PhysicalConstants::PLANCK_CONSTANT,
+            speed_of_light: PhysicalConstants::SPEED_OF_LIGHT,
+            boltzmann_constant: PhysicalConstants::BOLTZMANN_CONSTANT,
+            gas_constant: PhysicalConstants::GAS_CONSTANT,
+            avogadro_number: PhysicalConstants::AVOGADRO_NUMBER,
+            electron_charge: PhysicalConstants::ELECTRON_CHARGE,
+            electron_mass: PhysicalConstants::ELECTRON_MASS,
+            proton_mass: PhysicalConstants::PROTON_MASS,
+            fine_structure_constant: PhysicalConstants::FINE_STRUCTURE_CONSTANT,
+            wien_displacement_constant: PhysicalConstants::WIEN_DISPLACEMENT_CONSTANT,
+            stefan_boltzmann_constant: PhysicalConstants::STEFAN_BOLTZMANN_CONSTANT,
+        }
+    }
+}
+
+impl Default for ConstantsSet {
+    fn default() -> Self {
+        ConstantsSet::codata_2018()
+    }
 }
 
 /// Physical constants
@@ -87,4 +994,299 @@ impl PhysicalConstants {
     pub const ELECTRON_CHARGE: f64 = 1.602176634e-19;     // C
     pub const ELECTRON_MASS: f64 = 9.1093837015e-31;      // kg
     pub const PROTON_MASS: f64 = 1.67262192369e-27;       // kg
-}
\ No newline at end of file
+    pub const DEUTERON_MASS: f64 = 3.3435837724e-27;      // kg
+    pub const FINE_STRUCTURE_CONSTANT: f64 = 7.2973525693e-3; // dimensionless
+    pub const WIEN_DISPLACEMENT_CONSTANT: f64 = 2.897771955e-3; // m⋅K
+    pub const STEFAN_BOLTZMANN_CONSTANT: f64 = 5.670374419e-8; // W/(m²⋅K⁴)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantum_validator::QuantumValidator;
+    use crate::thermo_state_tracker::{ThermoStateTracker, ThermodynamicStateBuilder};
+    use fabric_contract_api::contract::Contract;
+    
+    fn result(is_valid: bool, validator_consensus: &[&str]) -> ValidationResult {
+        ValidationResult {
+            is_valid,
+            error_message: None,
+            confidence_score: 0.95,
+            validator_consensus: validator_consensus.iter().map(|name| name.to_string()).collect(),
+            timestamp: 1640995200,
+            failed_check: None,
+            notes: Vec::new(),
+        }
+    }
+    
+    #[test]
+    fn test_fingerprint_ignores_consensus_order() {
+        let forward = result(true, &["validator_001", "validator_002", "validator_003"]);
+        let shuffled = result(true, &["validator_003", "validator_001", "validator_002"]);
+        
+        assert_eq!(forward.fingerprint(), shuffled.fingerprint());
+        assert_eq!(forward.fingerprint().len(), 64);
+    }
+    
+    #[test]
+    fn test_fingerprint_sensitivity() {
+        let valid = result(true, &["validator_001"]);
+        let invalid = result(false, &["validator_001"]);
+        assert_ne!(valid.fingerprint(), invalid.fingerprint());
+        
+        let mut later = valid.clone();
+        later.timestamp += 60;
+        assert_eq!(valid.fingerprint(), later.fingerprint());
+        assert_ne!(valid.fingerprint_with_timestamp(), later.fingerprint_with_timestamp());
+    }
+    
+    fn validate_through_trait<V: Validator>(validator: &V, input: &V::Input) -> ValidationResult {
+        validator.validate(input).unwrap_or_else(|error| ValidationResult {
+            is_valid: false,
+            error_message: Some(error.to_string()),
+            confidence_score: 0.0,
+            validator_consensus: vec![],
+            timestamp: 0,
+            failed_check: Some(error.failed_check()),
+            notes: Vec::new(),
+        })
+    }
+    
+    #[test]
+    fn test_validators_through_common_trait() {
+        let frequency = 1.0 * PhysicalConstants::ELECTRON_CHARGE / PhysicalConstants::PLANCK_CONSTANT;
+        let transition = QuantumTransition {
+            electron_id: "test".to_string(),
+            initial_energy: 2.0,
+            final_energy: 1.0,
+            photon_energy: 1.0,
+            wavelength: PhysicalConstants::SPEED_OF_LIGHT / frequency * 1e9,
+            frequency,
+            timestamp: 1640995200,
+            validated: false,
+            validator_id: "test".to_string(),
+            relativistic: false,
+            declared_kind: None,
+            source_velocity_ms: None,
+            energy_uncertainty: None,
+            frequency_uncertainty: None,
+            nuclear_mass_kg: None,
+            orbital_angular_momentum: None,
+        };
+        
+        let initial_state = ThermodynamicState {
+            substance_id: "N2".to_string(),
+            temperature: 300.0,
+            pressure: 101325.0,
+            volume: 0.0246,
+            phase: PhaseState::Gas,
+            entropy: 191.6,
+            enthalpy: 8700.0,
+            gibbs_energy: 8700.0 - 300.0 * 191.6,
+            internal_energy: 8700.0 - 101325.0 * 0.0246,
+            timestamp: 1640995200,
+            validated: false,
+            population_inverted: false,
+        };
+        let mut final_state = initial_state.clone();
+        final_state.entropy = 192.0;
+        
+        let results = vec![
+            validate_through_trait(&QuantumValidator::new(), &transition),
+            validate_through_trait(&ThermoStateTracker::new(), &(initial_state, final_state)),
+        ];
+        
+        let summary = crate::utils::summarize_results(&results);
+        assert_eq!(summary.total, 2);
+        assert_eq!(summary.valid_count, 2);
+    }
+    
+    #[test]
+    fn test_codata_2018_matches_physical_constants() {
+        let constants = ConstantsSet::codata_2018();
+        
+        assert_eq!(constants.codata_version, 2018);
+        assert_eq!(constants.planck_constant, PhysicalConstants::PLANCK_CONSTANT);
+        assert_eq!(constants.speed_of_light, PhysicalConstants::SPEED_OF_LIGHT);
+        assert_eq!(constants.boltzmann_constant, PhysicalConstants::BOLTZMANN_CONSTANT);
+        assert_eq!(constants.gas_constant, PhysicalConstants::GAS_CONSTANT);
+        assert_eq!(constants.avogadro_number, PhysicalConstants::AVOGADRO_NUMBER);
+        assert_eq!(constants.electron_charge, PhysicalConstants::ELECTRON_CHARGE);
+        assert_eq!(constants.electron_mass, PhysicalConstants::ELECTRON_MASS);
+        assert_eq!(constants.proton_mass, PhysicalConstants::PROTON_MASS);
+        assert_eq!(constants.fine_structure_constant, PhysicalConstants::FINE_STRUCTURE_CONSTANT);
+        assert_eq!(constants.wien_displacement_constant, PhysicalConstants::WIEN_DISPLACEMENT_CONSTANT);
+        assert_eq!(constants.stefan_boltzmann_constant, PhysicalConstants::STEFAN_BOLTZMANN_CONSTANT);
+        assert_eq!(ConstantsSet::default(), constants);
+    }
+    
+    fn encodable_transition() -> QuantumTransition {
+        QuantumTransition {
+            electron_id: "e".to_string(),
+            initial_energy: 2.0,
+            final_energy: 1.0,
+            photon_energy: 1.0,
+            wavelength: 0.5,
+            frequency: 4.0,
+            timestamp: 0x0102,
+            validated: true,
+            validator_id: "v".to_string(),
+            relativistic: false,
+            declared_kind: Some(TransitionKind::Emission),
+            source_velocity_ms: None,
+            energy_uncertainty: None,
+            frequency_uncertainty: None,
+            nuclear_mass_kg: None,
+            orbital_angular_momentum: None,
+        }
+    }
+    
+    #[test]
+    fn test_transition_bytes_round_trip() {
+        let mut transition = encodable_transition();
+        transition.electron_id = "electron-λ".to_string();
+        transition.photon_energy = f64::NAN;
+        transition.declared_kind = None;
+        transition.source_velocity_ms = Some(-1.5e4);
+        transition.frequency_uncertainty = Some(2.5e6);
+        transition.orbital_angular_momentum = Some((1, 0));
+        
+        let decoded = QuantumTransition::from_bytes(&transition.to_bytes()).unwrap();
+        assert_eq!(decoded, transition);
+        
+        let mut bytes = transition.to_bytes();
+        bytes.push(0);
+        assert_eq!(
+            QuantumTransition::from_bytes(&bytes),
+            Err(DecodeError::TrailingBytes { count: 1 }),
+        );
+        bytes.truncate(10);
+        assert!(matches!(
+            QuantumTransition::from_bytes(&bytes),
+            Err(DecodeError::UnexpectedEnd { .. }),
+        ));
+    }
+    
+    #[test]
+    fn test_transition_byte_layout() {
+        let expected: Vec<u8> = [
+            &[1, 0, 0, 0, 0, 0, 0, 0, b'e'][..],
+            &[0, 0, 0, 0, 0, 0, 0x00, 0x40], // 2.0
+            &[0, 0, 0, 0, 0, 0, 0xf0, 0x3f], // 1.0
+            &[0, 0, 0, 0, 0, 0, 0xf0, 0x3f], // 1.0
+            &[0, 0, 0, 0, 0, 0, 0xe0, 0x3f], // 0.5
+            &[0, 0, 0, 0, 0, 0, 0x10, 0x40], // 4.0
+            &[0x02, 0x01, 0, 0, 0, 0, 0, 0],
+            &[1],
+            &[1, 0, 0, 0, 0, 0, 0, 0, b'v'],
+            &[0],
+            &[1],
+            &[0],
+            &[0],
+            &[0],
+            &[0],
+            &[0],
+        ]
+        .concat();
+        
+        assert_eq!(encodable_transition().to_bytes(), expected);
+    }
+    
+    #[test]
+    fn test_inputs_dump_parseable_json_through_trait_object() {
+        let mut transition = encodable_transition();
+        transition.photon_energy = f64::NAN;
+        let state = ThermodynamicStateBuilder::new("H2O").build(&ThermoStateTracker::new()).unwrap();
+        let inputs: Vec<Box<dyn ValidatableInput>> = vec![Box::new(transition), Box::new(state)];
+        
+        let documents: Vec<serde_json::Value> = inputs
+            .iter()
+            .map(|input| serde_json::from_str(&input.to_debug_json()).unwrap())
+            .collect();
+        
+        let key = |snake: &'static str, camel: &'static str| {
+            if cfg!(feature = "camel-case") { camel } else { snake }
+        };
+        assert_eq!(documents[0][key("electron_id", "electronId")], "e");
+        assert!(documents[0][key("photon_energy", "photonEnergy")].is_null());
+        assert_eq!(documents[1][key("substance_id", "substanceId")], "H2O");
+    }
+    
+    #[test]
+    fn test_combine_modes() {
+        let mut first = result(true, &["validator_001", "validator_002"]);
+        first.confidence_score = 0.9;
+        let mut second = result(true, &["validator_002", "validator_003"]);
+        second.confidence_score = 0.8;
+        second.timestamp += 60;
+        
+        let conservative = first.combine(&second, CombineMode::Conservative);
+        assert!(conservative.is_valid);
+        assert_eq!(conservative.confidence_score, 0.8);
+        assert_eq!(conservative.validator_consensus, ["validator_001", "validator_002", "validator_003"]);
+        assert_eq!(conservative.timestamp, second.timestamp);
+        
+        let independent = first.combine(&second, CombineMode::Independent);
+        assert!((independent.confidence_score - 0.72).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_combine_valid_with_invalid_is_invalid() {
+        let valid = result(true, &["validator_001"]);
+        let mut invalid = result(false, &[]);
+        invalid.confidence_score = 0.0;
+        invalid.error_message = Some("Quantum selection rules violated".to_string());
+        invalid.failed_check = Some(FailedCheck::SelectionRules);
+        
+        let combined = valid.combine(&invalid, CombineMode::Conservative);
+        assert!(!combined.is_valid);
+        assert_eq!(combined.confidence_score, 0.0);
+        assert_eq!(combined.error_message.as_deref(), Some("Quantum selection rules violated"));
+        assert_eq!(combined.failed_check, Some(FailedCheck::SelectionRules));
+    }
+    
+    #[test]
+    fn test_camel_case_document_round_trip() {
+        let document = r#"{
+            "electronId": "e-42",
+            "initialEnergy": 3.4,
+            "finalEnergy": 1.51,
+            "photonEnergy": 1.89,
+            "wavelength": 656.0,
+            "frequency": 4.57e14,
+            "timestamp": 1640995200,
+            "validated": false,
+            "validatorId": "node-7",
+            "sourceVelocityMs": 1500.0
+        }"#;
+        
+        let transition: QuantumTransition = serde_json::from_str(document).unwrap();
+        assert_eq!(transition.electron_id, "e-42");
+        assert_eq!(transition.photon_energy, 1.89);
+        assert_eq!(transition.source_velocity_ms, Some(1500.0));
+        
+        let round_trip: QuantumTransition =
+            serde_json::from_str(&serde_json::to_string(&transition).unwrap()).unwrap();
+        assert_eq!(round_trip, transition);
+    }
+    
+    #[cfg(feature = "camel-case")]
+    #[test]
+    fn test_camel_case_feature_renames_fields_only() {
+        let transition = encodable_transition();
+        let json = serde_json::to_string(&transition).unwrap();
+        assert!(json.contains("\"initialEnergy\":"));
+        assert!(!json.contains("initial_energy"));
+        
+        // Map keys are data, not field names, and keep their spelling
+        let mut substances = crate::utils::builtin_substances();
+        substances.retain(|id, _| id == "H2O");
+        substances.insert("H2O_ice".to_string(), substances["H2O"].clone());
+        let json = serde_json::to_string(&substances).unwrap();
+        assert!(json.contains("\"H2O_ice\":{"));
+        assert!(json.contains("\"triplePointTemperature\":273.16"));
+        
+        let round_trip: HashMap<String, SubstanceProperties> = serde_json::from_str(&json).unwrap();
+        assert_eq!(round_trip["H2O_ice"].triple_point_pressure, Some(611.657));
+    }
+}
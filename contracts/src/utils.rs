@@ -0,0 +1,1138 @@
+//! Utility functions shared across smart contracts
+//!
+//! Physics helpers built on the crate's physical constants
+
+use crate::types::{
+    PhysicalConstants, QuantumTransition, SpectralBand, SubstanceProperties, ValidationResult,
+    ValidationSummary,
+};
+use std::collections::HashMap;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum UtilsError {
+    #[error("Invalid {parameter}: {value}")]
+    InvalidParameter { parameter: &'static str, value: f64 },
+    #[error("Length mismatch: {levels} energy levels but {degeneracies} degeneracies")]
+    LengthMismatch { levels: usize, degeneracies: usize },
+    #[error("No energy levels given")]
+    NoEnergyLevels,
+    #[error("Invalid quantum numbers: n = {n}, l = {l}")]
+    InvalidQuantumNumbers { n: u32, l: u32 },
+    #[error("Degeneracy overflows u32 for {quantum_number} = {value}")]
+    DegeneracyOverflow { quantum_number: &'static str, value: u32 },
+}
+
+#[derive(Error, Debug)]
+pub enum ParseError {
+    #[error("Line {line}: missing {column} column")]
+    MissingColumn { line: usize, column: &'static str },
+    #[error("Line {line}: invalid {column} value {value:?}")]
+    InvalidNumber { line: usize, column: &'static str, value: String },
+    #[error("Invalid ionization energy: {ionization_energy} eV")]
+    InvalidIonizationEnergy { ionization_energy: f64 },
+}
+
+/// Rejects values that are not strictly positive
+fn require_positive(parameter: &'static str, value: f64) -> Result<(), UtilsError> {
+    if value <= 0.0 {
+        return Err(UtilsError::InvalidParameter { parameter, value });
+    }
+    
+    Ok(())
+}
+
+/// Spectral radiance of a blackbody from Planck's law, in W/(sr⋅m³)
+///
+/// B_λ = (2hc²/λ⁵) ⋅ 1/(exp(hc/λkT) − 1). Returns `InvalidParameter` when
+/// the wavelength or temperature is not strictly positive.
+pub fn planck_spectral_radiance(
+    wavelength_m: f64,
+    temperature_k: f64,
+) -> Result<f64, UtilsError> {
+    require_positive("wavelength", wavelength_m)?;
+    require_positive("temperature", temperature_k)?;
+    
+    let h = PhysicalConstants::PLANCK_CONSTANT;
+    let c = PhysicalConstants::SPEED_OF_LIGHT;
+    let k = PhysicalConstants::BOLTZMANN_CONSTANT;
+    
+    let prefactor = 2.0 * h * c.powi(2) / wavelength_m.powi(5);
+    let x = h * c / (wavelength_m * k * temperature_k);
+    
+    // 1/(eˣ − 1) = e⁻ˣ/(1 − e⁻ˣ), which underflows to zero instead of
+    // overflowing for large x and keeps precision for small x
+    let boltzmann_factor = (-x).exp();
+    
+    Ok(prefactor * boltzmann_factor / -(-x).exp_m1())
+}
+
+/// Total blackbody emittance in W/m² from the Stefan–Boltzmann law: M = σT⁴
+pub fn integrated_blackbody_emittance(temperature_k: f64) -> Result<f64, UtilsError> {
+    require_positive("temperature", temperature_k)?;
+    
+    Ok(PhysicalConstants::STEFAN_BOLTZMANN_CONSTANT * temperature_k.powi(4))
+}
+
+/// Total blackbody emittance in W/m² by numerically integrating Planck's law
+///
+/// M = π∫B_λ dλ, evaluated with Simpson's rule in ln λ from 10⁻² to 10³ times
+/// the peak wavelength, which captures all but a negligible fraction of the
+/// power. Serves as a cross-check of `integrated_blackbody_emittance`.
+pub fn numerical_blackbody_emittance(temperature_k: f64) -> Result<f64, UtilsError> {
+    let peak = wien_peak_wavelength(temperature_k)?;
+    let (lower, upper) = ((peak * 1e-2).ln(), (peak * 1e3).ln());
+    let intervals = 2000;
+    let step = (upper - lower) / intervals as f64;
+    
+    // dλ = λ d(ln λ)
+    let integrand = |ln_wavelength: f64| -> Result<f64, UtilsError> {
+        let wavelength = ln_wavelength.exp();
+        Ok(planck_spectral_radiance(wavelength, temperature_k)? * wavelength)
+    };
+    
+    let mut sum = integrand(lower)? + integrand(upper)?;
+    for i in 1..intervals {
+        let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+        sum += weight * integrand(lower + i as f64 * step)?;
+    }
+    
+    Ok(std::f64::consts::PI * sum * step / 3.0)
+}
+
+/// Peak blackbody wavelength in metres from Wien's displacement law: λ_max = b/T
+pub fn wien_peak_wavelength(temperature_k: f64) -> Result<f64, UtilsError> {
+    require_positive("temperature", temperature_k)?;
+    
+    Ok(PhysicalConstants::WIEN_DISPLACEMENT_CONSTANT / temperature_k)
+}
+
+/// Blackbody temperature in Kelvin inferred from its peak wavelength: T = b/λ_max
+pub fn wien_temperature_from_peak(wavelength_m: f64) -> Result<f64, UtilsError> {
+    require_positive("wavelength", wavelength_m)?;
+    
+    Ok(PhysicalConstants::WIEN_DISPLACEMENT_CONSTANT / wavelength_m)
+}
+
+/// de Broglie wavelength of a massive particle in metres: λ = h/(mv)
+pub fn de_broglie_wavelength(mass_kg: f64, velocity_ms: f64) -> Result<f64, UtilsError> {
+    require_positive("mass", mass_kg)?;
+    require_positive("velocity", velocity_ms)?;
+    
+    Ok(PhysicalConstants::PLANCK_CONSTANT / (mass_kg * velocity_ms))
+}
+
+/// Thermal de Broglie wavelength in metres: Λ = h/√(2πmkT)
+pub fn thermal_de_broglie_wavelength(
+    mass_kg: f64,
+    temperature_k: f64,
+) -> Result<f64, UtilsError> {
+    require_positive("mass", mass_kg)?;
+    require_positive("temperature", temperature_k)?;
+    
+    let thermal_momentum = (2.0 * std::f64::consts::PI * mass_kg *
+        PhysicalConstants::BOLTZMANN_CONSTANT * temperature_k).sqrt();
+    
+    Ok(PhysicalConstants::PLANCK_CONSTANT / thermal_momentum)
+}
+
+/// WKB transmission probability through a rectangular potential barrier
+///
+/// T ≈ exp(−2κd) with κ = √(2m(V − E))/ℏ for a particle of energy E below a
+/// barrier of height V and width d; particles at or above the barrier pass with T = 1.
+pub fn tunneling_probability(
+    barrier_height_ev: f64,
+    particle_energy_ev: f64,
+    barrier_width_m: f64,
+    mass_kg: f64,
+) -> Result<f64, UtilsError> {
+    require_positive("barrier width", barrier_width_m)?;
+    require_positive("mass", mass_kg)?;
+    
+    let deficit_ev = barrier_height_ev - particle_energy_ev;
+    if deficit_ev <= 0.0 {
+        return Ok(1.0);
+    }
+    
+    let reduced_planck = PhysicalConstants::PLANCK_CONSTANT / (2.0 * std::f64::consts::PI);
+    let kappa = (2.0 * mass_kg * ev_to_joules(deficit_ev)).sqrt() / reduced_planck;
+    
+    Ok((-2.0 * kappa * barrier_width_m).exp())
+}
+
+/// Natural linewidth in eV of a level with lifetime τ: ΔE = ℏ/τ
+pub fn natural_linewidth_ev(lifetime_s: f64) -> Result<f64, UtilsError> {
+    require_positive("lifetime", lifetime_s)?;
+    
+    let reduced_planck = PhysicalConstants::PLANCK_CONSTANT / (2.0 * std::f64::consts::PI);
+    Ok(joules_to_ev(reduced_planck / lifetime_s))
+}
+
+/// Lifetime in seconds implied by a natural linewidth in eV: τ = ℏ/ΔE
+pub fn lifetime_from_linewidth(linewidth_ev: f64) -> Result<f64, UtilsError> {
+    require_positive("linewidth", linewidth_ev)?;
+    
+    let reduced_planck = PhysicalConstants::PLANCK_CONSTANT / (2.0 * std::f64::consts::PI);
+    Ok(reduced_planck / ev_to_joules(linewidth_ev))
+}
+
+/// Converts electron-volts to joules: E[J] = E[eV] ⋅ e
+pub fn ev_to_joules(energy_ev: f64) -> f64 {
+    energy_ev * PhysicalConstants::ELECTRON_CHARGE
+}
+
+/// Converts joules to electron-volts: E[eV] = E[J] / e
+pub fn joules_to_ev(energy_j: f64) -> f64 {
+    energy_j / PhysicalConstants::ELECTRON_CHARGE
+}
+
+/// Photon energy in eV for a frequency in Hz: E = hf / e
+pub fn hz_to_ev(frequency_hz: f64) -> f64 {
+    joules_to_ev(PhysicalConstants::PLANCK_CONSTANT * frequency_hz)
+}
+
+/// Photon energy in eV for a wavelength in nm: E = hc / (λ ⋅ 10⁻⁹) / e
+pub fn wavelength_nm_to_ev(wavelength_nm: f64) -> f64 {
+    joules_to_ev(PhysicalConstants::PLANCK_CONSTANT * PhysicalConstants::SPEED_OF_LIGHT /
+        (wavelength_nm * 1e-9))
+}
+
+/// Validates a temperature already in Kelvin, rejecting values below absolute zero
+pub fn kelvin(temperature_k: f64) -> Result<f64, UtilsError> {
+    if temperature_k < 0.0 {
+        return Err(UtilsError::InvalidParameter {
+            parameter: "temperature",
+            value: temperature_k,
+        });
+    }
+    
+    Ok(temperature_k)
+}
+
+/// Converts Celsius to Kelvin: T[K] = T[°C] + 273.15, rejecting values below −273.15 °C
+pub fn celsius_to_kelvin(temperature_c: f64) -> Result<f64, UtilsError> {
+    kelvin(temperature_c + 273.15)
+}
+
+/// Converts Fahrenheit to Kelvin: T[K] = (T[°F] − 32) ⋅ 5/9 + 273.15, rejecting below −459.67 °F
+pub fn fahrenheit_to_kelvin(temperature_f: f64) -> Result<f64, UtilsError> {
+    kelvin((temperature_f - 32.0) * 5.0 / 9.0 + 273.15)
+}
+
+/// Converts bar to Pascal: 1 bar = 10⁵ Pa
+pub fn bar_to_pascal(pressure_bar: f64) -> f64 {
+    pressure_bar * 1e5
+}
+
+/// Converts standard atmospheres to Pascal: 1 atm = 101325 Pa
+pub fn atm_to_pascal(pressure_atm: f64) -> f64 {
+    pressure_atm * 101325.0
+}
+
+/// Classifies a photon by wavelength in nm
+///
+/// Upper band edges are 0.01 nm (gamma), 10 nm (X-ray), 380 nm (ultraviolet),
+/// 750 nm (visible), 1 mm (infrared), and 1 m (microwave). A wavelength exactly
+/// on an edge falls in the shorter-wavelength band.
+pub fn classify_band(wavelength_nm: f64) -> Result<SpectralBand, UtilsError> {
+    require_positive("wavelength", wavelength_nm)?;
+    
+    let band = if wavelength_nm <= 0.01 {
+        SpectralBand::Gamma
+    } else if wavelength_nm <= 10.0 {
+        SpectralBand::XRay
+    } else if wavelength_nm <= 380.0 {
+        SpectralBand::Ultraviolet
+    } else if wavelength_nm <= 750.0 {
+        SpectralBand::Visible
+    } else if wavelength_nm <= 1e6 {
+        SpectralBand::Infrared
+    } else if wavelength_nm <= 1e9 {
+        SpectralBand::Microwave
+    } else {
+        SpectralBand::Radio
+    };
+    
+    Ok(band)
+}
+
+/// Photon momentum in kg⋅m/s for a photon energy in eV: p = E/c
+pub fn photon_momentum(photon_energy_ev: f64) -> f64 {
+    ev_to_joules(photon_energy_ev) / PhysicalConstants::SPEED_OF_LIGHT
+}
+
+/// Radiation pressure in Pa on a surface of reflectivity R ∈ [0, 1]: P = (1 + R)I/c
+pub fn radiation_pressure(intensity_w_m2: f64, reflectivity: f64) -> Result<f64, UtilsError> {
+    if !(0.0..=1.0).contains(&reflectivity) {
+        return Err(UtilsError::InvalidParameter {
+            parameter: "reflectivity",
+            value: reflectivity,
+        });
+    }
+    
+    Ok((1.0 + reflectivity) * intensity_w_m2 / PhysicalConstants::SPEED_OF_LIGHT)
+}
+
+/// Most probable Maxwell–Boltzmann speed in m/s: √(2kT/m)
+pub fn maxwell_boltzmann_most_probable_speed(
+    mass_kg: f64,
+    temperature_k: f64,
+) -> Result<f64, UtilsError> {
+    require_positive("mass", mass_kg)?;
+    require_positive("temperature", temperature_k)?;
+    
+    Ok((2.0 * PhysicalConstants::BOLTZMANN_CONSTANT * temperature_k / mass_kg).sqrt())
+}
+
+/// Mean Maxwell–Boltzmann speed in m/s: √(8kT/πm)
+pub fn maxwell_boltzmann_mean_speed(
+    mass_kg: f64,
+    temperature_k: f64,
+) -> Result<f64, UtilsError> {
+    require_positive("mass", mass_kg)?;
+    require_positive("temperature", temperature_k)?;
+    
+    Ok((8.0 * PhysicalConstants::BOLTZMANN_CONSTANT * temperature_k /
+        (std::f64::consts::PI * mass_kg)).sqrt())
+}
+
+/// Root-mean-square Maxwell–Boltzmann speed in m/s: √(3kT/m)
+pub fn maxwell_boltzmann_rms_speed(
+    mass_kg: f64,
+    temperature_k: f64,
+) -> Result<f64, UtilsError> {
+    require_positive("mass", mass_kg)?;
+    require_positive("temperature", temperature_k)?;
+    
+    Ok((3.0 * PhysicalConstants::BOLTZMANN_CONSTANT * temperature_k / mass_kg).sqrt())
+}
+
+/// Final temperature in Kelvin of a reversible adiabatic pressure change
+///
+/// T_f = T_i(P_f/P_i)^((γ−1)/γ) for an ideal gas with heat capacity ratio γ > 1.
+pub fn adiabatic_temperature(
+    t_initial: f64,
+    p_initial: f64,
+    p_final: f64,
+    gamma: f64,
+) -> Result<f64, UtilsError> {
+    require_positive("temperature", t_initial)?;
+    require_positive("initial pressure", p_initial)?;
+    require_positive("final pressure", p_final)?;
+    
+    if gamma <= 1.0 {
+        return Err(UtilsError::InvalidParameter { parameter: "heat capacity ratio", value: gamma });
+    }
+    
+    Ok(t_initial * (p_final / p_initial).powf((gamma - 1.0) / gamma))
+}
+
+/// Entropy change in J/K of an ideal gas between two temperatures and volumes
+///
+/// ΔS = n[C_v ln(T_f/T_i) + R ln(V_f/V_i)] with `cv` the molar heat capacity at
+/// constant volume in J/(mol⋅K).
+pub fn ideal_gas_entropy_change(
+    n_moles: f64,
+    cv: f64,
+    t_i: f64,
+    t_f: f64,
+    v_i: f64,
+    v_f: f64,
+) -> Result<f64, UtilsError> {
+    require_positive("amount of substance", n_moles)?;
+    require_positive("initial temperature", t_i)?;
+    require_positive("final temperature", t_f)?;
+    require_positive("initial volume", v_i)?;
+    require_positive("final volume", v_f)?;
+    
+    Ok(n_moles * (cv * (t_f / t_i).ln() + PhysicalConstants::GAS_CONSTANT * (v_f / v_i).ln()))
+}
+
+/// Canonical partition function Z = Σ gᵢ exp(−Eᵢ/kT) over levels given in eV
+///
+/// Energies are measured from the lowest level, so Z → g_ground as T → 0 and
+/// Z → Σ gᵢ as T → ∞; this also keeps every exponent non-positive so the sum
+/// cannot overflow. Multiply by exp(−E_min/kT) for the absolute-zero reference.
+pub fn partition_function(
+    levels_ev: &[f64],
+    degeneracies: &[u32],
+    temperature_k: f64,
+) -> Result<f64, UtilsError> {
+    if levels_ev.len() != degeneracies.len() {
+        return Err(UtilsError::LengthMismatch {
+            levels: levels_ev.len(),
+            degeneracies: degeneracies.len(),
+        });
+    }
+    
+    if levels_ev.is_empty() {
+        return Err(UtilsError::NoEnergyLevels);
+    }
+    
+    require_positive("temperature", temperature_k)?;
+    
+    let kt = PhysicalConstants::BOLTZMANN_CONSTANT * temperature_k /
+        PhysicalConstants::ELECTRON_CHARGE; // eV
+    let ground = levels_ev.iter().copied().fold(f64::INFINITY, f64::min);
+    
+    Ok(levels_ev
+        .iter()
+        .zip(degeneracies)
+        .map(|(energy, &g)| g as f64 * (-(energy - ground) / kt).exp())
+        .sum())
+}
+
+/// Mean occupation of a fermion state: f = 1/(exp((E − µ)/kT) + 1)
+///
+/// At T = 0 this is the Fermi step: 1 below µ, 0 above it, and ½ at E = µ.
+pub fn fermi_dirac_occupation(
+    energy_ev: f64,
+    chemical_potential_ev: f64,
+    temperature_k: f64,
+) -> Result<f64, UtilsError> {
+    kelvin(temperature_k)?;
+    
+    let excess = energy_ev - chemical_potential_ev;
+    if temperature_k == 0.0 {
+        return Ok(match excess {
+            excess if excess < 0.0 => 1.0,
+            excess if excess > 0.0 => 0.0,
+            _ => 0.5,
+        });
+    }
+    
+    let kt = joules_to_ev(PhysicalConstants::BOLTZMANN_CONSTANT * temperature_k);
+    Ok(1.0 / ((excess / kt).exp() + 1.0))
+}
+
+/// Mean occupation of a boson state: n = 1/(exp((E − µ)/kT) − 1)
+///
+/// The occupation diverges as E → µ and is negative below it, so energies at or
+/// below the chemical potential return `InvalidParameter`. At T = 0 every state
+/// above µ is empty.
+pub fn bose_einstein_occupation(
+    energy_ev: f64,
+    chemical_potential_ev: f64,
+    temperature_k: f64,
+) -> Result<f64, UtilsError> {
+    kelvin(temperature_k)?;
+    
+    let excess = energy_ev - chemical_potential_ev;
+    if excess <= 0.0 {
+        return Err(UtilsError::InvalidParameter {
+            parameter: "energy above chemical potential",
+            value: excess,
+        });
+    }
+    
+    if temperature_k == 0.0 {
+        return Ok(0.0);
+    }
+    
+    let kt = joules_to_ev(PhysicalConstants::BOLTZMANN_CONSTANT * temperature_k);
+    Ok(1.0 / (excess / kt).exp_m1())
+}
+
+/// Degeneracy of hydrogenic shell n including spin: g = 2n²
+///
+/// n = 0 is not a bound level and yields 0. Returns `DegeneracyOverflow` for
+/// n ≥ 46341, where 2n² no longer fits in a u32.
+pub fn hydrogenic_degeneracy(n: u32) -> Result<u32, UtilsError> {
+    n.checked_mul(n)
+        .and_then(|square| square.checked_mul(2))
+        .ok_or(UtilsError::DegeneracyOverflow { quantum_number: "n", value: n })
+}
+
+/// Degeneracy of hydrogenic subshell (n, l) including spin: g = 2(2l + 1)
+///
+/// Returns `InvalidQuantumNumbers` unless 0 ≤ l < n, and `DegeneracyOverflow`
+/// for l ≥ 2³⁰, where 2(2l + 1) no longer fits in a u32.
+pub fn level_degeneracy(n: u32, l: u32) -> Result<u32, UtilsError> {
+    if l >= n {
+        return Err(UtilsError::InvalidQuantumNumbers { n, l });
+    }
+    
+    l.checked_mul(2)
+        .and_then(|twice| twice.checked_add(1))
+        .and_then(|orbitals| orbitals.checked_mul(2))
+        .ok_or(UtilsError::DegeneracyOverflow { quantum_number: "l", value: l })
+}
+
+/// Built-in substance property table keyed by `ThermodynamicState::substance_id`
+///
+/// Helium-4 has no solid–liquid–gas triple point and does not solidify at
+/// ordinary pressures, so its triple point and fusion enthalpy are absent.
+pub fn builtin_substances() -> HashMap<String, SubstanceProperties> {
+    let substances = [
+        ("H2O", SubstanceProperties {
+            name: "Water".to_string(),
+            molar_mass: 0.018015,
+            critical_temperature: 647.096,
+            critical_pressure: 22.064e6,
+            triple_point_temperature: Some(273.16),
+            triple_point_pressure: Some(611.657),
+            enthalpy_of_fusion: Some(6010.0),
+            enthalpy_of_vaporization: 40660.0,
+        }),
+        ("CO2", SubstanceProperties {
+            name: "Carbon dioxide".to_string(),
+            molar_mass: 0.04401,
+            critical_temperature: 304.13,
+            critical_pressure: 7.3773e6,
+            triple_point_temperature: Some(216.58),
+            triple_point_pressure: Some(518.0e3),
+            enthalpy_of_fusion: Some(9020.0),
+            enthalpy_of_vaporization: 15330.0,
+        }),
+        ("N2", SubstanceProperties {
+            name: "Nitrogen".to_string(),
+            molar_mass: 0.028014,
+            critical_temperature: 126.19,
+            critical_pressure: 3.3958e6,
+            triple_point_temperature: Some(63.151),
+            triple_point_pressure: Some(12.52e3),
+            enthalpy_of_fusion: Some(720.0),
+            enthalpy_of_vaporization: 5560.0,
+        }),
+        ("He", SubstanceProperties {
+            name: "Helium-4".to_string(),
+            molar_mass: 0.0040026,
+            critical_temperature: 5.1953,
+            critical_pressure: 0.22746e6,
+            triple_point_temperature: None,
+            triple_point_pressure: None,
+            enthalpy_of_fusion: None,
+            enthalpy_of_vaporization: 83.3,
+        }),
+    ];
+    
+    substances
+        .into_iter()
+        .map(|(id, properties)| (id.to_string(), properties))
+        .collect()
+}
+
+/// Summarizes batch validation results, counting failures by error message
+pub fn summarize_results(results: &[ValidationResult]) -> ValidationSummary {
+    let valid_count = results.iter().filter(|result| result.is_valid).count();
+    let mut failure_reasons = HashMap::new();
+    
+    for result in results.iter().filter(|result| !result.is_valid) {
+        let reason = result
+            .error_message
+            .clone()
+            .unwrap_or_else(|| "unspecified".to_string());
+        *failure_reasons.entry(reason).or_insert(0) += 1;
+    }
+    
+    let mean_confidence = if results.is_empty() {
+        0.0
+    } else {
+        results.iter().map(|result| result.confidence_score).sum::<f64>() / results.len() as f64
+    };
+    
+    ValidationSummary {
+        total: results.len(),
+        valid_count,
+        invalid_count: results.len() - valid_count,
+        mean_confidence,
+        failure_reasons,
+    }
+}
+
+/// Renders validation results as RFC 4180 CSV with a header row
+///
+/// Validator names are joined with `;`, and a missing error message is an
+/// empty field. Records are separated by CRLF.
+pub fn results_to_csv(results: &[ValidationResult]) -> String {
+    let mut csv = String::from("is_valid,confidence_score,validator_consensus,timestamp,error_message\r\n");
+    
+    for result in results {
+        let fields = [
+            result.is_valid.to_string(),
+            result.confidence_score.to_string(),
+            csv_field(&result.validator_consensus.join(";")),
+            result.timestamp.to_string(),
+            csv_field(result.error_message.as_deref().unwrap_or("")),
+        ];
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+    
+    csv
+}
+
+/// Parses NIST-style tab-separated transition data
+///
+/// Columns are wavelength (nm), frequency (Hz), upper level (eV), and lower
+/// level (eV), describing emission from the upper to the lower level. Blank
+/// lines and lines starting with `#` are skipped. The energy levels are
+/// required; an empty or absent wavelength or frequency is derived from the
+/// photon energy. Errors carry the 1-based line number.
+///
+/// NIST measures levels upward from the ground state at 0 eV, while this crate
+/// uses positive binding energies. Each level is therefore mapped to
+/// `ionization_energy − level`, putting the ground state at the ionization
+/// energy. As in the built-in hydrogen series, the more tightly bound lower
+/// level becomes `initial_energy` and the upper level `final_energy`, so a
+/// parsed Lyman α line reads 13.6 → 3.4 eV.
+pub fn parse_nist_tsv(
+    input: &str,
+    ionization_energy: f64,
+) -> Result<Vec<QuantumTransition>, ParseError> {
+    if !(ionization_energy > 0.0 && ionization_energy.is_finite()) {
+        return Err(ParseError::InvalidIonizationEnergy { ionization_energy });
+    }
+    
+    let mut transitions = Vec::new();
+    
+    for (index, raw) in input.lines().enumerate() {
+        let line = index + 1;
+        let trimmed = raw.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        
+        let fields: Vec<&str> = raw.split('\t').collect();
+        let wavelength = tsv_field(&fields, 0, "wavelength", line)?;
+        let frequency = tsv_field(&fields, 1, "frequency", line)?;
+        let upper = tsv_field(&fields, 2, "upper level", line)?
+            .ok_or(ParseError::MissingColumn { line, column: "upper level" })?;
+        let lower = tsv_field(&fields, 3, "lower level", line)?
+            .ok_or(ParseError::MissingColumn { line, column: "lower level" })?;
+        
+        let initial_energy = ionization_energy - lower;
+        let final_energy = ionization_energy - upper;
+        let photon_energy = (initial_energy - final_energy).abs();
+        let frequency = frequency.unwrap_or_else(|| {
+            ev_to_joules(photon_energy) / PhysicalConstants::PLANCK_CONSTANT
+        });
+        let wavelength = wavelength
+            .unwrap_or_else(|| PhysicalConstants::SPEED_OF_LIGHT / frequency * 1e9);
+        
+        transitions.push(QuantumTransition {
+            electron_id: format!("nist-{}", line),
+            initial_energy,
+            final_energy,
+            photon_energy,
+            wavelength,
+            frequency,
+            timestamp: 0,
+            validated: false,
+            validator_id: "nist".to_string(),
+            relativistic: false,
+            declared_kind: None,
+            source_velocity_ms: None,
+            energy_uncertainty: None,
+            frequency_uncertainty: None,
+            nuclear_mass_kg: None,
+            orbital_angular_momentum: None,
+        });
+    }
+    
+    Ok(transitions)
+}
+
+/// Reads an optional numeric TSV column, treating an absent or empty field as `None`
+fn tsv_field(
+    fields: &[&str],
+    index: usize,
+    column: &'static str,
+    line: usize,
+) -> Result<Option<f64>, ParseError> {
+    let value = match fields.get(index).map(|field| field.trim()) {
+        Some(value) if !value.is_empty() => value,
+        _ => return Ok(None),
+    };
+    
+    value
+        .parse()
+        .map(Some)
+        .map_err(|_| ParseError::InvalidNumber { line, column, value: value.to_string() })
+}
+
+/// Quotes a CSV field if it contains a delimiter, quote, or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantum_validator::QuantumValidator;
+    use fabric_contract_api::contract::Contract;
+    
+    const HYDROGEN_IONIZATION_ENERGY: f64 = 13.598434; // eV
+    
+    fn peak_wavelength(temperature_k: f64) -> f64 {
+        let mut peak = (0.0, 0.0);
+        
+        for i in 1..=10_000 {
+            let wavelength = i as f64 * 1e-9;
+            let radiance = planck_spectral_radiance(wavelength, temperature_k).unwrap();
+            if radiance > peak.1 {
+                peak = (wavelength, radiance);
+            }
+        }
+        
+        peak.0
+    }
+    
+    #[test]
+    fn test_planck_peak_follows_wien_law() {
+        let peak_3000 = peak_wavelength(3000.0);
+        let peak_6000 = peak_wavelength(6000.0);
+        
+        // Hotter bodies peak at shorter wavelengths
+        assert!(peak_6000 < peak_3000);
+        
+        // λ_max ⋅ T = b
+        let wien = PhysicalConstants::WIEN_DISPLACEMENT_CONSTANT;
+        assert!((peak_3000 * 3000.0 - wien).abs() < 1e-5);
+        assert!((peak_6000 * 6000.0 - wien).abs() < 1e-5);
+    }
+    
+    #[test]
+    fn test_planck_large_exponent_does_not_overflow() {
+        let radiance = planck_spectral_radiance(1e-9, 1.0).unwrap();
+        assert_eq!(radiance, 0.0);
+    }
+    
+    #[test]
+    fn test_planck_rejects_non_positive_inputs() {
+        assert!(planck_spectral_radiance(0.0, 300.0).is_err());
+        assert!(planck_spectral_radiance(500e-9, -1.0).is_err());
+    }
+    
+    #[test]
+    fn test_electron_de_broglie_wavelength() {
+        // Electron at 1e6 m/s has λ ≈ 7.27 Å
+        let wavelength = de_broglie_wavelength(PhysicalConstants::ELECTRON_MASS, 1e6).unwrap();
+        assert!((wavelength - 7.274e-10).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_helium_thermal_wavelength_at_room_temperature() {
+        // Helium-4 at 300 K has Λ ≈ 0.50 Å
+        let helium_mass = 4.0026 * 1.66053907e-27;
+        let wavelength = thermal_de_broglie_wavelength(helium_mass, 300.0).unwrap();
+        assert!((wavelength - 5.04e-11).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_de_broglie_rejects_non_positive_inputs() {
+        assert!(de_broglie_wavelength(0.0, 1e6).is_err());
+        assert!(de_broglie_wavelength(PhysicalConstants::ELECTRON_MASS, -1.0).is_err());
+        assert!(thermal_de_broglie_wavelength(1e-27, 0.0).is_err());
+    }
+    
+    #[test]
+    fn test_tunneling_decays_exponentially_with_width() {
+        let electron = PhysicalConstants::ELECTRON_MASS;
+        let thin = tunneling_probability(5.0, 4.0, 1e-10, electron).unwrap();
+        let double = tunneling_probability(5.0, 4.0, 2e-10, electron).unwrap();
+        let triple = tunneling_probability(5.0, 4.0, 3e-10, electron).unwrap();
+        
+        // A 1 eV deficit gives κ ≈ 5.12 nm⁻¹
+        assert!((thin.ln() + 1.0246).abs() < 1e-3);
+        assert!((double.ln() - 2.0 * thin.ln()).abs() < 1e-12);
+        assert!((triple.ln() - 3.0 * thin.ln()).abs() < 1e-12);
+        
+        assert_eq!(tunneling_probability(5.0, 6.0, 1e-10, electron).unwrap(), 1.0);
+        assert!(tunneling_probability(5.0, 4.0, 0.0, electron).is_err());
+        assert!(tunneling_probability(5.0, 4.0, 1e-10, -electron).is_err());
+    }
+    
+    #[test]
+    fn test_natural_linewidth_round_trip() {
+        // A 1 ns lifetime broadens the line by ~0.66 µeV
+        let linewidth = natural_linewidth_ev(1e-9).unwrap();
+        assert!((linewidth - 6.582e-7).abs() < 1e-10);
+        
+        let lifetime = lifetime_from_linewidth(linewidth).unwrap();
+        assert!((lifetime - 1e-9).abs() <= 1e-9 * 1e-12);
+        
+        assert!(natural_linewidth_ev(0.0).is_err());
+        assert!(lifetime_from_linewidth(-1e-7).is_err());
+    }
+    
+    #[test]
+    fn test_ideal_gas_entropy_change() {
+        let cv = 1.5 * PhysicalConstants::GAS_CONSTANT;
+        
+        // Isothermal doubling of two moles: ΔS = nR ln 2
+        let isothermal = ideal_gas_entropy_change(2.0, cv, 300.0, 300.0, 0.01, 0.02).unwrap();
+        let expected = 2.0 * PhysicalConstants::GAS_CONSTANT * std::f64::consts::LN_2;
+        assert!((isothermal - expected).abs() < 1e-12);
+        
+        // Isochoric cooling lowers the entropy
+        assert!(ideal_gas_entropy_change(1.0, cv, 300.0, 200.0, 0.01, 0.01).unwrap() < 0.0);
+        
+        assert!(ideal_gas_entropy_change(0.0, cv, 300.0, 300.0, 0.01, 0.02).is_err());
+        assert!(ideal_gas_entropy_change(1.0, cv, 300.0, -1.0, 0.01, 0.02).is_err());
+        assert!(ideal_gas_entropy_change(1.0, cv, 300.0, 300.0, 0.01, 0.0).is_err());
+    }
+    
+    #[test]
+    fn test_parse_nist_tsv() {
+        let input = "# wavelength\tfrequency\tupper\tlower\n\
+                     656.28\t4.568e14\t12.0875\t10.1988\n\
+                     \n\
+                     \t\t10.1988\t0.0\n";
+        
+        let transitions = parse_nist_tsv(input, HYDROGEN_IONIZATION_ENERGY).unwrap();
+        assert_eq!(transitions.len(), 2);
+        
+        let h_alpha = &transitions[0];
+        assert_eq!(h_alpha.wavelength, 656.28);
+        assert_eq!(h_alpha.frequency, 4.568e14);
+        assert!((h_alpha.photon_energy - 1.8887).abs() < 1e-9);
+        
+        // Wavelength and frequency are derived when left empty
+        let lyman_alpha = &transitions[1];
+        assert!((lyman_alpha.photon_energy - 10.1988).abs() < 1e-9);
+        assert!((wavelength_nm_to_ev(lyman_alpha.wavelength) - 10.1988).abs() < 1e-9);
+        assert!((hz_to_ev(lyman_alpha.frequency) - 10.1988).abs() < 1e-9);
+        
+        // The ground state sits at the ionization energy
+        assert_eq!(lyman_alpha.initial_energy, HYDROGEN_IONIZATION_ENERGY);
+        assert!((lyman_alpha.final_energy - 3.399634).abs() < 1e-9);
+        
+        assert!(matches!(
+            parse_nist_tsv(input, 0.0),
+            Err(ParseError::InvalidIonizationEnergy { .. })
+        ));
+    }
+    
+    #[test]
+    fn test_parsed_nist_lines_pass_validation() {
+        let input = "\t\t10.1988\t0.0\n\
+                     \t\t12.0875\t0.0\n\
+                     \t\t12.0875\t10.1988\n";
+        let validator = QuantumValidator::new();
+        
+        for transition in parse_nist_tsv(input, HYDROGEN_IONIZATION_ENERGY).unwrap() {
+            let result = validator.validate_transition(&transition);
+            assert!(result.is_ok(), "{}: {:?}", transition.electron_id, result);
+        }
+    }
+    
+    #[test]
+    fn test_parse_nist_tsv_reports_line_number() {
+        let input = "# header\n656.28\t4.568e14\t12.0875\t10.1988\n486.13\tabc\t12.7485\t10.1988\n";
+        match parse_nist_tsv(input, HYDROGEN_IONIZATION_ENERGY) {
+            Err(ParseError::InvalidNumber { line, column, value }) => {
+                assert_eq!(line, 3);
+                assert_eq!(column, "frequency");
+                assert_eq!(value, "abc");
+            }
+            other => panic!("expected invalid number, got {:?}", other),
+        }
+        
+        match parse_nist_tsv("434.05\t6.907e14\t13.0545", HYDROGEN_IONIZATION_ENERGY) {
+            Err(ParseError::MissingColumn { line: 1, column: "lower level" }) => {}
+            other => panic!("expected missing column, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_ev_joules_round_trip() {
+        for energy in [1e-6, 1e-3, 1.0, 13.6, 1e3, 1e9] {
+            let round_trip = joules_to_ev(ev_to_joules(energy));
+            assert!((round_trip - energy).abs() <= energy * 1e-12);
+        }
+    }
+    
+    #[test]
+    fn test_photon_energy_conversions() {
+        // Lyman alpha: 121.567 nm ≈ 2.466e15 Hz ≈ 10.2 eV
+        assert!((wavelength_nm_to_ev(121.567) - 10.199).abs() < 1e-3);
+        assert!((hz_to_ev(2.466e15) - 10.199).abs() < 1e-2);
+    }
+    
+    #[test]
+    fn test_maxwell_boltzmann_speed_ordering_for_nitrogen() {
+        let nitrogen_mass = 28.0134 * 1.66053907e-27;
+        
+        let most_probable = maxwell_boltzmann_most_probable_speed(nitrogen_mass, 300.0).unwrap();
+        let mean = maxwell_boltzmann_mean_speed(nitrogen_mass, 300.0).unwrap();
+        let rms = maxwell_boltzmann_rms_speed(nitrogen_mass, 300.0).unwrap();
+        
+        assert!(most_probable < mean);
+        assert!(mean < rms);
+        assert!((most_probable - 422.0).abs() < 1.0);
+        assert!((rms - 517.0).abs() < 1.0);
+    }
+    
+    #[test]
+    fn test_maxwell_boltzmann_rejects_non_positive_inputs() {
+        assert!(maxwell_boltzmann_most_probable_speed(0.0, 300.0).is_err());
+        assert!(maxwell_boltzmann_mean_speed(1e-26, -5.0).is_err());
+        assert!(maxwell_boltzmann_rms_speed(-1e-26, 300.0).is_err());
+    }
+    
+    #[test]
+    fn test_photon_momentum() {
+        // A 1 eV photon carries 5.34e-28 kg⋅m/s
+        assert!((photon_momentum(1.0) - 5.344e-28).abs() < 1e-31);
+    }
+    
+    #[test]
+    fn test_radiation_pressure_reflector_doubles_absorber() {
+        // Solar constant at Earth
+        let intensity = 1361.0;
+        let absorber = radiation_pressure(intensity, 0.0).unwrap();
+        let reflector = radiation_pressure(intensity, 1.0).unwrap();
+        
+        assert!((absorber - 4.54e-6).abs() < 1e-8);
+        assert!((reflector - 2.0 * absorber).abs() < 1e-18);
+        assert!(radiation_pressure(intensity, 1.5).is_err());
+    }
+    
+    fn result(is_valid: bool, confidence_score: f64, error_message: Option<&str>) -> ValidationResult {
+        ValidationResult {
+            is_valid,
+            error_message: error_message.map(str::to_string),
+            confidence_score,
+            validator_consensus: vec![],
+            timestamp: 1640995200,
+            failed_check: None,
+            notes: Vec::new(),
+        }
+    }
+    
+    #[test]
+    fn test_summarize_mixed_batch() {
+        let results = vec![
+            result(true, 1.0, None),
+            result(true, 0.8, None),
+            result(false, 0.0, Some("Quantum selection rules violated")),
+            result(false, 0.0, Some("Quantum selection rules violated")),
+            result(false, 0.2, Some("Frequency mismatch")),
+        ];
+        
+        let summary = summarize_results(&results);
+        assert_eq!(summary.total, 5);
+        assert_eq!(summary.valid_count, 2);
+        assert_eq!(summary.invalid_count, 3);
+        assert!((summary.mean_confidence - 0.4).abs() < 1e-12);
+        assert_eq!(summary.failure_reasons["Quantum selection rules violated"], 2);
+        assert_eq!(summary.failure_reasons["Frequency mismatch"], 1);
+    }
+    
+    #[test]
+    fn test_summarize_empty_batch() {
+        let summary = summarize_results(&[]);
+        assert_eq!(summary.total, 0);
+        assert_eq!(summary.mean_confidence, 0.0);
+        assert!(summary.failure_reasons.is_empty());
+    }
+    
+    #[test]
+    fn test_wien_solar_temperature() {
+        let temperature = wien_temperature_from_peak(500e-9).unwrap();
+        assert!((temperature - 5800.0).abs() < 10.0);
+        
+        let peak = wien_peak_wavelength(temperature).unwrap();
+        assert!((peak - 500e-9).abs() < 1e-15);
+        
+        assert!(wien_peak_wavelength(0.0).is_err());
+        assert!(wien_temperature_from_peak(-1.0).is_err());
+    }
+    
+    #[test]
+    fn test_builtin_substances_lookup() {
+        let substances = builtin_substances();
+        
+        let water = &substances["H2O"];
+        assert_eq!(water.triple_point_temperature, Some(273.16));
+        assert_eq!(water.triple_point_pressure, Some(611.657));
+        
+        assert!(substances.contains_key("CO2"));
+        assert!(substances.contains_key("N2"));
+        assert!(substances.contains_key("He"));
+        assert!(!substances.contains_key("unobtainium"));
+    }
+    
+    #[test]
+    fn test_temperature_and_pressure_conversions() {
+        assert_eq!(celsius_to_kelvin(0.0).unwrap(), 273.15);
+        assert!((fahrenheit_to_kelvin(212.0).unwrap() - 373.15).abs() < 1e-9);
+        assert_eq!(bar_to_pascal(1.0), 1e5);
+        assert_eq!(atm_to_pascal(1.0), 101325.0);
+        
+        assert!(celsius_to_kelvin(-273.16).is_err());
+        assert!(fahrenheit_to_kelvin(-460.0).is_err());
+    }
+    
+    #[test]
+    fn test_classify_band() {
+        assert_eq!(classify_band(550.0).unwrap(), SpectralBand::Visible);
+        assert_eq!(classify_band(0.01).unwrap(), SpectralBand::Gamma);
+        assert_eq!(classify_band(121.6).unwrap(), SpectralBand::Ultraviolet);
+        assert_eq!(classify_band(380.0).unwrap(), SpectralBand::Ultraviolet);
+        assert_eq!(classify_band(1e7).unwrap(), SpectralBand::Microwave);
+        assert_eq!(classify_band(2e9).unwrap(), SpectralBand::Radio);
+        assert!(classify_band(0.0).is_err());
+    }
+    
+    #[test]
+    fn test_two_level_partition_function_limits() {
+        // Ground state g = 1 and a doubly degenerate level 1 eV above it
+        let levels = [-13.6, -12.6];
+        let degeneracies = [1, 2];
+        
+        let cold = partition_function(&levels, &degeneracies, 10.0).unwrap();
+        assert!((cold - 1.0).abs() < 1e-12);
+        
+        let hot = partition_function(&levels, &degeneracies, 1e9).unwrap();
+        assert!((hot - 3.0).abs() < 1e-3);
+    }
+    
+    #[test]
+    fn test_fermi_step_at_zero_temperature() {
+        assert_eq!(fermi_dirac_occupation(4.9, 5.0, 0.0).unwrap(), 1.0);
+        assert_eq!(fermi_dirac_occupation(5.1, 5.0, 0.0).unwrap(), 0.0);
+        assert_eq!(fermi_dirac_occupation(5.0, 5.0, 0.0).unwrap(), 0.5);
+        
+        // A 1 K tail is already indistinguishable from the step 10 meV away from µ
+        assert!(fermi_dirac_occupation(4.99, 5.0, 1.0).unwrap() > 1.0 - 1e-12);
+        assert!(fermi_dirac_occupation(5.01, 5.0, 1.0).unwrap() < 1e-12);
+        
+        assert_eq!(bose_einstein_occupation(0.1, 0.0, 0.0).unwrap(), 0.0);
+        assert!(bose_einstein_occupation(0.0, 0.0, 300.0).is_err());
+        assert!(fermi_dirac_occupation(0.0, 0.0, -1.0).is_err());
+    }
+    
+    #[test]
+    fn test_occupations_approach_boltzmann_limit() {
+        // Far above µ both statistics reduce to exp(−(E − µ)/kT)
+        let temperature = 10000.0;
+        let kt = joules_to_ev(PhysicalConstants::BOLTZMANN_CONSTANT * temperature);
+        let energy = 20.0 * kt;
+        let boltzmann = (-energy / kt).exp();
+        
+        let fermi = fermi_dirac_occupation(energy, 0.0, temperature).unwrap();
+        let bose = bose_einstein_occupation(energy, 0.0, temperature).unwrap();
+        assert!(fermi < boltzmann && bose > boltzmann);
+        assert!((fermi - boltzmann).abs() / boltzmann < 1e-8);
+        assert!((bose - boltzmann).abs() / boltzmann < 1e-8);
+    }
+    
+    #[test]
+    fn test_partition_function_rejects_invalid_input() {
+        assert!(matches!(
+            partition_function(&[0.0, 1.0], &[1], 300.0),
+            Err(UtilsError::LengthMismatch { levels: 2, degeneracies: 1 }),
+        ));
+        assert!(matches!(partition_function(&[], &[], 300.0), Err(UtilsError::NoEnergyLevels)));
+        assert!(partition_function(&[0.0], &[1], 0.0).is_err());
+    }
+    
+    #[test]
+    fn test_planck_integral_matches_stefan_boltzmann() {
+        let analytic = integrated_blackbody_emittance(1000.0).unwrap();
+        let numerical = numerical_blackbody_emittance(1000.0).unwrap();
+        
+        assert!((analytic - 56703.74419).abs() < 1e-6);
+        assert!((numerical - analytic).abs() / analytic < 1e-3);
+        assert!(integrated_blackbody_emittance(0.0).is_err());
+    }
+    
+    /// Minimal RFC 4180 reader for checking `results_to_csv` output
+    fn parse_csv(csv: &str) -> Vec<Vec<String>> {
+        let mut records = vec![];
+        let mut record = vec![];
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = csv.chars().peekable();
+        
+        while let Some(c) = chars.next() {
+            match (quoted, c) {
+                (true, '"') if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                (true, '"') => quoted = false,
+                (true, c) => field.push(c),
+                (false, '"') => quoted = true,
+                (false, ',') => record.push(std::mem::take(&mut field)),
+                (false, '\r') => {}
+                (false, '\n') => {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                (false, c) => field.push(c),
+            }
+        }
+        
+        records
+    }
+    
+    #[test]
+    fn test_results_to_csv_escapes_fields() {
+        let mut failed = result(false, 0.0, Some("Invalid energy level: 1, \"ground\" state"));
+        failed.validator_consensus = vec!["quantum_validator".to_string(), "thermo_state_tracker".to_string()];
+        let results = vec![result(true, 0.95, None), failed];
+        
+        let csv = results_to_csv(&results);
+        assert!(csv.contains("\"Invalid energy level: 1, \"\"ground\"\" state\""));
+        
+        let records = parse_csv(&csv);
+        assert_eq!(records.len(), 3);
+        assert_eq!(
+            records[0],
+            ["is_valid", "confidence_score", "validator_consensus", "timestamp", "error_message"],
+        );
+        assert_eq!(records[1], ["true", "0.95", "", "1640995200", ""]);
+        assert_eq!(records[2][0], "false");
+        assert_eq!(records[2][2], "quantum_validator;thermo_state_tracker");
+        assert_eq!(records[2][4], "Invalid energy level: 1, \"ground\" state");
+    }
+    
+    #[test]
+    fn test_hydrogenic_degeneracies() {
+        assert_eq!(hydrogenic_degeneracy(1).unwrap(), 2);
+        assert_eq!(hydrogenic_degeneracy(2).unwrap(), 8);
+        assert_eq!(level_degeneracy(2, 1).unwrap(), 6);
+        
+        // The subshells of a shell add up to the shell degeneracy
+        let shell: u32 = (0..3).map(|l| level_degeneracy(3, l).unwrap()).sum();
+        assert_eq!(shell, hydrogenic_degeneracy(3).unwrap());
+        
+        assert!(matches!(
+            level_degeneracy(2, 2),
+            Err(UtilsError::InvalidQuantumNumbers { n: 2, l: 2 }),
+        ));
+        
+        // Largest representable shell and subshell, then the first that overflow
+        assert_eq!(hydrogenic_degeneracy(46340).unwrap(), 4_294_791_200);
+        assert!(matches!(
+            hydrogenic_degeneracy(46341),
+            Err(UtilsError::DegeneracyOverflow { quantum_number: "n", value: 46341 }),
+        ));
+        assert_eq!(level_degeneracy(u32::MAX, (1 << 30) - 1).unwrap(), u32::MAX - 1);
+        assert!(matches!(
+            level_degeneracy(u32::MAX, 1 << 30),
+            Err(UtilsError::DegeneracyOverflow { quantum_number: "l", .. }),
+        ));
+    }
+    
+    #[test]
+    fn test_adiabatic_compression_of_diatomic_gas() {
+        // Compressing air tenfold from room temperature heats it to about 579 K
+        let heated = adiabatic_temperature(300.0, 1e5, 1e6, 1.4).unwrap();
+        assert!((heated - 579.2).abs() < 0.1);
+        
+        // Expanding back recovers the initial temperature
+        let cooled = adiabatic_temperature(heated, 1e6, 1e5, 1.4).unwrap();
+        assert!((cooled - 300.0).abs() < 1e-9);
+        
+        assert!(adiabatic_temperature(300.0, 1e5, 1e6, 1.0).is_err());
+        assert!(adiabatic_temperature(300.0, 0.0, 1e6, 1.4).is_err());
+    }
+}
\ No newline at end of file